@@ -7,10 +7,10 @@ echo "🌐 Building WASM for web..."
 echo "📦 Building WASM package..."
 wasm-pack build --target web --out-dir web/svelte-app/src/pkg
 
-# Copy dictionary to svelte-app public directory
-echo "📋 Copying dictionary..."
+# Pack dictionary into the svelte-app public directory
+echo "📋 Packing dictionary..."
 mkdir -p web/svelte-app/public
-cp data/dictionary.txt web/svelte-app/public/
+cargo run --release --bin dictionary-builder -- --pack data/dictionary.txt --output web/svelte-app/public/dictionary.bin
 
 echo "✅ Web build complete!"
 echo "🚀 WASM files are in 'web/svelte-app/src/pkg/'"
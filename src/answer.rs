@@ -0,0 +1,239 @@
+use crate::board::{Board, Side};
+use crate::dictionary::{Dictionary, Word};
+use crate::solver::Solution;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Something wrong with a proposed answer. Words are numbered from 0, in the order they're played.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AnswerProblem {
+    NoWords,
+    NotInDictionary { word: usize, text: String },
+    LetterNotOnBoard { word: usize, text: String, letter: char },
+    /// The letters at `position` and `position + 1` of a word are on the same side of the board.
+    SameSide { word: usize, text: String, position: usize, side: Side },
+    /// Word `word + 1` doesn't start with the letter word `word` ends with.
+    ChainBreak { word: usize, from: String, to: String },
+    /// Letters on the board which no word uses.
+    Uncovered { letters: Vec<char> },
+}
+
+impl fmt::Display for AnswerProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerProblem::NoWords => write!(f, "there are no words"),
+            AnswerProblem::NotInDictionary { text, .. } => write!(f, "'{}' is not in the dictionary", text),
+            AnswerProblem::LetterNotOnBoard { text, letter, .. } => {
+                write!(f, "'{}' uses '{}', which is not on the board", text, letter)
+            }
+            AnswerProblem::SameSide { text, position, side, .. } => {
+                let letters: String = text.chars().skip(*position).take(2).collect();
+                write!(f, "'{}' plays \"{}\", but both letters are on {}", text, letters, side)
+            }
+            AnswerProblem::ChainBreak { from, to, .. } => {
+                write!(f, "'{}' does not start with the last letter of '{}'", to, from)
+            }
+            AnswerProblem::Uncovered { letters } => {
+                write!(f, "these letters are never used: {}", letters.iter().collect::<String>())
+            }
+        }
+    }
+}
+
+/// The verdict on a player's answer, from `Board::check_answer`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AnswerCheck {
+    pub words: Vec<String>,
+    /// Everything which stops the answer solving the board. Empty if it does.
+    pub problems: Vec<AnswerProblem>,
+    /// For an answer which works but is redundant, the fewest of its words which already cover the board.
+    /// Redundancy follows `Solution::redactable_subsequences`, so the solver never suggests answers like these.
+    pub covering_words: Option<Vec<String>>,
+}
+
+impl AnswerCheck {
+    pub(crate) fn new(board: &Board, words: &[&str], dictionary: &Dictionary) -> Self {
+        let side_of: HashMap<char, usize> = board
+            .sides
+            .iter()
+            .enumerate()
+            .flat_map(|(index, side)| side.chars().map(move |ch| (ch, index)))
+            .collect();
+        let mut problems = Vec::new();
+        let mut found = Vec::new();
+
+        if words.is_empty() {
+            problems.push(AnswerProblem::NoWords);
+        }
+
+        for (index, &text) in words.iter().enumerate() {
+            match dictionary.words.iter().find(|w| w.word == text) {
+                Some(word) => found.push(word.clone()),
+                None => problems.push(AnswerProblem::NotInDictionary {
+                    word: index,
+                    text: text.to_string(),
+                }),
+            }
+
+            let chars: Vec<char> = text.chars().collect();
+            if let Some(&letter) = chars.iter().find(|ch| !side_of.contains_key(ch)) {
+                problems.push(AnswerProblem::LetterNotOnBoard {
+                    word: index,
+                    text: text.to_string(),
+                    letter,
+                });
+                continue;
+            }
+            for (position, pair) in chars.windows(2).enumerate() {
+                if side_of[&pair[0]] == side_of[&pair[1]] {
+                    problems.push(AnswerProblem::SameSide {
+                        word: index,
+                        text: text.to_string(),
+                        position,
+                        side: Side {
+                            index: side_of[&pair[0]],
+                            side_count: board.sides.len(),
+                        },
+                    });
+                }
+            }
+        }
+
+        for (index, pair) in words.windows(2).enumerate() {
+            if pair[0].chars().last() != pair[1].chars().next() {
+                problems.push(AnswerProblem::ChainBreak {
+                    word: index,
+                    from: pair[0].to_string(),
+                    to: pair[1].to_string(),
+                });
+            }
+        }
+
+        let uncovered: Vec<char> = board
+            .sides
+            .iter()
+            .flat_map(|side| side.chars())
+            .filter(|&ch| !words.iter().any(|word| word.contains(ch)))
+            .collect();
+        if !uncovered.is_empty() && !words.is_empty() {
+            problems.push(AnswerProblem::Uncovered { letters: uncovered });
+        }
+
+        let covering_words = if problems.is_empty() {
+            shortest_redaction(board, found)
+        } else {
+            None
+        };
+
+        AnswerCheck {
+            words: words.iter().map(|w| w.to_string()).collect(),
+            problems,
+            covering_words,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    pub fn is_redundant(&self) -> bool {
+        self.covering_words.is_some()
+    }
+}
+
+/// The smallest redactable subsequence of `words` which still covers the board, if there is one.
+fn shortest_redaction(board: &Board, words: Vec<Word>) -> Option<Vec<String>> {
+    let solution = Solution::new(words);
+    let covers_board = |indices: &[usize]| {
+        board
+            .sides
+            .iter()
+            .flat_map(|side| side.chars())
+            .all(|ch| indices.iter().any(|&i| solution.words[i].word.contains(ch)))
+    };
+
+    solution
+        .redactable_subsequences()
+        .into_iter()
+        .filter(|indices| covers_board(indices))
+        .min_by_key(|indices| indices.len())
+        .map(|indices| indices.iter().map(|&i| solution.words[i].word.clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{board, dictionary};
+
+    #[test]
+    fn test_valid_answer() {
+        let check = board().check_answer(&["foxglove", "equity"], &dictionary());
+
+        assert!(check.is_valid());
+        assert!(!check.is_redundant());
+    }
+
+    #[test]
+    fn test_problems() {
+        let check = board().check_answer(&["fig", "foxglove", "quiz"], &dictionary());
+
+        assert!(!check.is_valid());
+        assert_eq!(
+            check.problems,
+            vec![
+                AnswerProblem::SameSide {
+                    word: 0,
+                    text: "fig".to_string(),
+                    position: 0,
+                    side: Side { index: 1, side_count: 4 },
+                },
+                AnswerProblem::SameSide {
+                    word: 0,
+                    text: "fig".to_string(),
+                    position: 1,
+                    side: Side { index: 1, side_count: 4 },
+                },
+                AnswerProblem::NotInDictionary {
+                    word: 2,
+                    text: "quiz".to_string(),
+                },
+                AnswerProblem::LetterNotOnBoard {
+                    word: 2,
+                    text: "quiz".to_string(),
+                    letter: 'z',
+                },
+                AnswerProblem::ChainBreak {
+                    word: 0,
+                    from: "fig".to_string(),
+                    to: "foxglove".to_string(),
+                },
+                AnswerProblem::ChainBreak {
+                    word: 1,
+                    from: "foxglove".to_string(),
+                    to: "quiz".to_string(),
+                },
+                AnswerProblem::Uncovered { letters: vec!['y', 't'] },
+            ]
+        );
+        assert_eq!(check.problems[0].to_string(), "'fig' plays \"fi\", but both letters are on the right side");
+    }
+
+    #[test]
+    fn test_uncovered_letters() {
+        let check = board().check_answer(&["foxglove", "elf"], &dictionary());
+
+        assert_eq!(check.problems, vec![AnswerProblem::Uncovered { letters: vec!['y', 'q', 'i', 't', 'u'] }]);
+        assert!(board().check_answer(&[], &dictionary()).problems == vec![AnswerProblem::NoWords]);
+    }
+
+    #[test]
+    fn test_redundant_answer() {
+        // Everything "you" covers, "equity" already has
+        let check = board().check_answer(&["foxglove", "equity", "you"], &dictionary());
+
+        assert!(check.is_valid());
+        assert_eq!(check.covering_words, Some(vec!["foxglove".to_string(), "equity".to_string()]));
+    }
+}
@@ -1,19 +1,44 @@
+use crate::answer::AnswerCheck;
 use crate::dictionary::Dictionary;
-use std::collections::{HashMap, HashSet};
+use crate::digraph::DigraphSet;
+use crate::error::Error;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-const SIDES_DISPLAY: &[&str] = &["top", "right", "bottom", "left"];
+pub const MIN_SIDES: usize = 3;
+const SQUARE_SIDES_DISPLAY: &[&str] = &["top", "right", "bottom", "left"];
+
+/// One side of a board, as reported in errors. `index` counts from 0, out of `side_count` sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Side {
+    pub index: usize,
+    pub side_count: usize,
+}
+
+/// Square boards use the familiar top/right/bottom/left names; other shapes have no obvious
+/// orientation, so their sides are numbered from 1.
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.side_count == SQUARE_SIDES_DISPLAY.len() {
+            write!(f, "the {} side", SQUARE_SIDES_DISPLAY[self.index])
+        } else {
+            write!(f, "side {}", self.index + 1)
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Board {
     pub sides: Vec<String>,
-    pub digraphs: HashSet<String>,
+    pub digraphs: DigraphSet,
 }
 
 impl Board {
-    pub fn from_sides(sides: Vec<String>) -> io::Result<Self> {
+    pub fn from_sides(sides: Vec<String>) -> Result<Self, Error> {
         Self::validate_sides_structure(&sides)?;
         Self::validate_sides_content(&sides)?;
 
@@ -23,70 +48,47 @@ impl Board {
         Ok(game)
     }
 
-    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
         let sides: Vec<String> = reader
             .lines()
-            .map_while(Result::ok)
-            .map(|s| s.to_lowercase())
-            .collect();
+            .map(|line| line.map(|s| s.to_lowercase()))
+            .collect::<Result<_, _>>()?;
 
         Self::from_sides(sides)
     }
 
-    fn validate_sides_structure(sides: &[String]) -> io::Result<()> {
-        if sides.len() != 4 {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Game must contain exactly 4 sides, found {}", sides.len()),
-            ));
+    /// Boards may have any number of sides from three upwards, and sides may differ in length.
+    fn validate_sides_structure(sides: &[String]) -> Result<(), Error> {
+        if sides.len() < MIN_SIDES {
+            return Err(Error::WrongSideCount { found: sides.len() });
         }
 
-        if sides.iter().any(|side| side.is_empty()) {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Empty sides are not allowed",
-            ));
-        }
-
-        let first_len = sides[0].len();
-        for (i, side) in sides.iter().enumerate() {
-            if side.len() != first_len {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("All sides must have the same length. The {} side has length {} but the {} side has length {}", 
-                        SIDES_DISPLAY[0], first_len, SIDES_DISPLAY[i], side.len())
-                ));
-            }
+        if let Some(index) = sides.iter().position(|side| side.is_empty()) {
+            return Err(Error::EmptySide {
+                side: Side { index, side_count: sides.len() },
+            });
         }
 
         Ok(())
     }
 
-    fn validate_sides_content(sides: &[String]) -> io::Result<()> {
+    fn validate_sides_content(sides: &[String]) -> Result<(), Error> {
         let mut seen_chars: HashMap<char, usize> = HashMap::new();
+        let side = |index| Side { index, side_count: sides.len() };
 
-        for (side_num, side) in sides.iter().enumerate() {
-            for c in side.chars() {
+        for (side_num, side_letters) in sides.iter().enumerate() {
+            for c in side_letters.chars() {
                 if !c.is_ascii_lowercase() {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("Invalid character '{}' on the {} side. Only lowercase ASCII letters are allowed", 
-                            c, SIDES_DISPLAY[side_num])
-                    ));
+                    return Err(Error::InvalidChar { ch: c, side: side(side_num) });
                 }
 
                 if let Some(previous_side) = seen_chars.insert(c, side_num) {
-                    let error = if previous_side == side_num {
-                        format!("Duplicate letter '{}' found on the {} side", c, SIDES_DISPLAY[side_num])
-                    } else {
-                        format!(
-                            "Duplicate letter '{}' found on the {} side and the {} side",
-                            c, SIDES_DISPLAY[previous_side], SIDES_DISPLAY[side_num]
-                        )
-                    };
-                    return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+                    return Err(Error::DuplicateLetter {
+                        ch: c,
+                        sides: (side(previous_side), side(side_num)),
+                    });
                 }
             }
         }
@@ -94,15 +96,14 @@ impl Board {
         Ok(())
     }
 
-    fn playable_digraphs(sides: &[String]) -> HashSet<String> {
-        let mut digraphs = HashSet::new();
+    fn playable_digraphs(sides: &[String]) -> DigraphSet {
+        let mut digraphs = DigraphSet::new();
         for (i, side) in sides.iter().enumerate() {
             for c1 in side.chars() {
                 for (j, other_side) in sides.iter().enumerate() {
                     if i != j {
                         for c2 in other_side.chars() {
-                            let digraph = format!("{}{}", c1, c2);
-                            digraphs.insert(digraph);
+                            digraphs.insert(c1, c2);
                         }
                     }
                 }
@@ -112,18 +113,20 @@ impl Board {
     }
 
     pub fn playable_dictionary(&self, dictionary: &Dictionary) -> Dictionary {
-        // Eliminate any digraphs on this board which are totally impossible in the whole dictionary, e.g. 'vz', 'zq'
-        let usable_digraphs: HashSet<&String> =
-            self.digraphs.intersection(&dictionary.digraphs).collect();
-
-        // Then cut it down to words which are playable on this board
+        // A word is playable on this board if every one of its digraphs is
         let playable_words = dictionary
             .words
             .iter()
-            .filter(|word| word.digraphs.iter().all(|d| usable_digraphs.contains(d)))
+            .filter(|word| word.digraphs.is_subset(&self.digraphs))
             .cloned()
             .collect();
 
         Dictionary::from_words(playable_words)
     }
+
+    /// Check a player's answer: that every word is in the dictionary and playable, that the words chain
+    /// together, and that they use every letter. Lists all the problems rather than stopping at the first.
+    pub fn check_answer(&self, words: &[&str], dictionary: &Dictionary) -> AnswerCheck {
+        AnswerCheck::new(self, words, dictionary)
+    }
 }
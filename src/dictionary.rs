@@ -1,6 +1,7 @@
-use std::collections::{HashSet};
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use crate::digraph::DigraphSet;
+use crate::error::Error;
+use crate::packed;
+use std::fs;
 use std::path::Path;
 
 /**
@@ -12,26 +13,13 @@ use std::path::Path;
 pub struct Word {
     pub word: String,
     pub frequency: i8,
-    pub digraphs: HashSet<String>,
+    pub digraphs: DigraphSet,
 }
 
 impl Word {
-    /// Extract digraphs (consecutive letter pairs) from a word
-    fn extract_digraphs(word: &str) -> HashSet<String> {
-        let chars: Vec<char> = word.chars().collect();
-        let mut digraphs = HashSet::new();
-
-        for i in 0..chars.len() - 1 {
-            let digraph = format!("{}{}", chars[i], chars[i + 1]);
-            digraphs.insert(digraph);
-        }
-
-        digraphs
-    }
-
     /// Create a new Word with the given word string and frequency
     pub fn new(word: String, frequency: i8) -> Self {
-        let digraphs = Self::extract_digraphs(&word);
+        let digraphs = DigraphSet::from_word(&word);
         Word {
             word,
             frequency,
@@ -43,17 +31,15 @@ impl Word {
 #[derive(Debug)]
 pub struct Dictionary {
     pub words: Vec<Word>,
-    pub digraphs: HashSet<String>,
+    pub digraphs: DigraphSet,
 }
 
 impl Dictionary {
     const DEFAULT_FREQUENCY: i8 = 15;
     pub fn from_words(words: Vec<Word>) -> Self {
-        let mut valid_digraphs = HashSet::new();
-
-        for word in &words {
-            valid_digraphs.extend(word.digraphs.iter().cloned());
-        }
+        let valid_digraphs = words
+            .iter()
+            .fold(DigraphSet::new(), |acc, word| acc.union(&word.digraphs));
 
         Dictionary {
             words,
@@ -71,7 +57,7 @@ impl Dictionary {
     }
 
     fn parse_word_line(line: &str) -> Option<Word> {
-        let mut parts = line.trim().split_whitespace();
+        let mut parts = line.split_whitespace();
         match (parts.next(), parts.next()) {
             (Some(word_str), Some(frequency_str)) => match frequency_str.parse::<i8>() {
                 Ok(frequency) => Some(Word::new(word_str.to_string(), frequency)),
@@ -81,41 +67,52 @@ impl Dictionary {
         }
     }
 
-    pub fn from_text(text: &str) -> Self {
-        let words: Vec<Word> = text
-            .lines()
-            .filter_map(Self::parse_word_line)
-            .collect();
-        Self::from_words(words)
+    /// Parse line `line_num` (counting from 0) of a dictionary. Blank lines are skipped.
+    fn parse_numbered_line(line_num: usize, line: &str) -> Option<Result<Word, Error>> {
+        if line.trim().is_empty() {
+            return None;
+        }
+        Some(Self::parse_word_line(line).ok_or_else(|| Error::MalformedDictionaryLine {
+            line: line_num + 1,
+            content: line.to_string(),
+        }))
     }
 
-    pub fn from_bytes(data: &[u8]) -> Result<Self, String> {
-        // Convert bytes to string and use existing text parsing
-        // This provides a foundation for future binary format support
-        match std::str::from_utf8(data) {
-            Ok(text) => Ok(Self::from_text(text)),
-            Err(e) => Err(format!("Invalid UTF-8 data: {}", e)),
+    /// Load a text dictionary of one word and its frequency per line. A bad line doesn't stop the rest
+    /// loading: it's skipped, and comes back as a `MalformedDictionaryLine` error alongside the dictionary,
+    /// for the caller to warn about or reject.
+    pub fn from_text(text: &str) -> (Self, Vec<Error>) {
+        let mut words = Vec::new();
+        let mut malformed = Vec::new();
+        for (line_num, line) in text.lines().enumerate() {
+            match Self::parse_numbered_line(line_num, line) {
+                Some(Ok(word)) => words.push(word),
+                Some(Err(e)) => malformed.push(e),
+                None => {}
+            }
         }
+        (Self::from_words(words), malformed)
     }
 
-    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let words: Vec<Word> = reader
-            .lines()
-            .map_while(Result::ok)
-            .enumerate()
-            .filter_map(|(line_num, s)| {
-                Self::parse_word_line(&s).or_else(|| {
-                    eprintln!("Invalid format on line {}: {}", line_num + 1, s);
-                    None
-                })
-            })
-            .collect();
-        Ok(Self::from_words(words))
+    /// Load either a packed binary dictionary, as written by `to_bytes`, or a text one, along with any
+    /// lines skipped from a text one as in `from_text`.
+    pub fn from_bytes(data: &[u8]) -> Result<(Self, Vec<Error>), Error> {
+        if packed::is_packed(data) {
+            return Ok((packed::decode(data)?, Vec::new()));
+        }
+        Ok(Self::from_text(std::str::from_utf8(data)?))
+    }
+
+    /// Write the packed binary format, which `from_bytes` loads much faster than text.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        packed::encode(self)
     }
-}
 
+    /// Load a dictionary file in either format, along with any lines skipped as in `from_text`.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<(Self, Vec<Error>), Error> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -123,13 +120,10 @@ mod tests {
 
     #[test]
     fn test_extract_digraphs_simple() {
-        let expected_digraphs: HashSet<String> = ["PI", "IR", "RA", "AT", "TE"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+        let expected_digraphs = ["at", "ir", "pi", "ra", "te"];
 
-        let word = Word::new("PIRATE".to_string(), 15);
-        assert_eq!(word.digraphs, expected_digraphs);
+        let word = Word::new("pirate".to_string(), 15);
+        assert_eq!(word.digraphs.to_strings(), expected_digraphs);
     }
 
     #[test]
@@ -137,7 +131,8 @@ mod tests {
         let text_data = "hello 25\nworld 30\ntest 15\n";
         let bytes = text_data.as_bytes();
 
-        let dictionary = Dictionary::from_bytes(bytes).expect("Should parse valid UTF-8");
+        let (dictionary, malformed) = Dictionary::from_bytes(bytes).expect("Should parse valid UTF-8");
+        assert!(malformed.is_empty());
 
         assert_eq!(dictionary.words.len(), 3);
         assert_eq!(dictionary.words[0].word, "hello");
@@ -148,14 +143,39 @@ mod tests {
         assert_eq!(dictionary.words[2].frequency, 15);
     }
 
+    #[test]
+    fn test_from_bytes_packed() {
+        let (dictionary, _) = Dictionary::from_text("hello 25\nworld 30\n");
+        let bytes = dictionary.to_bytes().unwrap();
+
+        let (loaded, _) = Dictionary::from_bytes(&bytes).expect("Should detect the packed format");
+        assert_eq!(loaded.words, dictionary.words);
+    }
+
     #[test]
     fn test_from_bytes_invalid_utf8() {
         let invalid_bytes = vec![0xFF, 0xFE, 0xFD]; // Invalid UTF-8
 
         let result = Dictionary::from_bytes(&invalid_bytes);
 
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Invalid UTF-8"));
+        assert!(matches!(result, Err(Error::InvalidUtf8(_))));
+    }
+
+    #[test]
+    fn test_from_text_malformed_line() {
+        let (dictionary, malformed) = Dictionary::from_text("hello 25\n\nworld\ntest 15\n");
+
+        // The good lines on either side still load
+        let words: Vec<&str> = dictionary.words.iter().map(|w| w.word.as_str()).collect();
+        assert_eq!(words, ["hello", "test"]);
+
+        match malformed.as_slice() {
+            [Error::MalformedDictionaryLine { line, content }] => {
+                assert_eq!(*line, 3);
+                assert_eq!(content, "world");
+            }
+            other => panic!("Expected one malformed line error, got {:?}", other),
+        }
     }
 }
 
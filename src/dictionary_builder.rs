@@ -1,10 +1,11 @@
 use clap::Parser;
+use letter_bounced::dictionary::Dictionary;
 use std::cmp::{min, Ordering};
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines, Result};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Lines, Result};
 use std::path::Path;
 
-/**
+/*
  * Build the standard word-list for boxchar, which will be a list of words which are playable, along with
  * how frequent they are in english. The word-list will then be sorted by frequency, which will make it
  * easier to display "good" solutions first.
@@ -34,6 +35,9 @@ use std::path::Path;
  *     $ cargo run dictionary-builder -- --frequencies data/google-ngrams-words-all.txt > /tmp/wordlist.txt
  *     $ sort -k 2,2rn -k 1 /tmp/wordlist.txt > data/wordlist.txt
  *
+ * The sorted word list can then be packed into the binary format, which the web app loads much faster, e.g.
+ *     $ cargo run --bin dictionary-builder -- --pack data/wordlist.txt --output data/wordlist.bin
+ *
  */
 
 #[derive(Parser)]
@@ -42,11 +46,19 @@ use std::path::Path;
     about = "Builds the dictionary wordlist for Boxchar from Google NGrams and the Scrabble dictionary"
 )]
 struct Args {
-    #[arg(long)]
-    frequencies: String,
+    #[arg(long, required_unless_present = "pack")]
+    frequencies: Option<String>,
 
     #[arg(long, default_value = "data/collins-scrabble-words-2019.txt")]
     scrabble: String,
+
+    /// Instead of building a word list, pack this sorted word list into the binary format
+    #[arg(long, conflicts_with = "frequencies", requires = "output")]
+    pack: Option<String>,
+
+    /// Where to write the packed dictionary
+    #[arg(long)]
+    output: Option<String>,
 }
 
 const MINIMUM_LENGTH: usize = 3;
@@ -82,12 +94,26 @@ fn path_string_to_line_iterator(path_string: &str) -> Result<Lines<BufReader<Fil
     Ok(lines)
 }
 
+fn pack(input: &str, output: &str) -> Result<()> {
+    let (dictionary, malformed) = Dictionary::from_path(input).map_err(io::Error::other)?;
+    for e in malformed {
+        eprintln!("Skipping dictionary line. {}", e);
+    }
+    let bytes = dictionary.to_bytes().map_err(io::Error::other)?;
+    fs::write(output, bytes)
+}
+
 fn main() -> std::io::Result<()> {
     env_logger::init();
     let args = Args::parse();
 
+    if let (Some(input), Some(output)) = (&args.pack, &args.output) {
+        return pack(input, output);
+    }
+    let frequencies = args.frequencies.as_deref().expect("clap requires --frequencies without --pack");
+
     let mut scrabble_lines = path_string_to_line_iterator(&args.scrabble)?;
-    let mut frequencies_lines = path_string_to_line_iterator(&args.frequencies)?;
+    let mut frequencies_lines = path_string_to_line_iterator(frequencies)?;
 
     let mut frequencies_line_current = frequencies_lines.next();
     let mut scrabble_line_current = scrabble_lines.next();
@@ -0,0 +1,181 @@
+use crate::playable::PlayableIndex;
+use crate::solver::Solver;
+use serde::Serialize;
+use std::fmt;
+
+/// Ratings below this are easy.
+const MEDIUM_FROM: f64 = 35.0;
+/// Ratings from this up are hard.
+const HARD_FROM: f64 = 65.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// What the rating is worked out from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DifficultyInputs {
+    /// `None` if there are no solutions within the word limit.
+    pub fewest_words: Option<usize>,
+    /// How many solutions there are of each length, from 1 word up to one more than the fewest.
+    pub solutions_by_length: Vec<(usize, u64)>,
+    /// The frequency of the rarest word in the best of the shortest solutions, the one whose words are most
+    /// common. Even the best answer is only as easy to think of as its hardest word.
+    pub best_frequency: Option<i8>,
+    /// The number of words which can be played on the board, the same as `Board::playable_dictionary` has.
+    pub playable_words: usize,
+}
+
+/// One part of the rating, from 0 (easy) to 1 (hard).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DifficultyFactor {
+    pub name: &'static str,
+    pub value: f64,
+    /// How much this counts towards the rating. The weights add up to 1.
+    pub weight: f64,
+}
+
+/// How hard a board is, from 0 to 100, and how that was worked out.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DifficultyRating {
+    pub rating: f64,
+    pub difficulty: Difficulty,
+    pub inputs: DifficultyInputs,
+    pub factors: Vec<DifficultyFactor>,
+}
+
+impl DifficultyRating {
+    /// Rate a board, looking for solutions of up to `max_words` words. A board with none is as hard as it gets.
+    pub fn new(index: &PlayableIndex, max_words: usize) -> Self {
+        let solver = Solver::from_index(index, 1).with_word_range(Solver::DEFAULT_MIN_WORDS, max_words);
+        let best = solver.best_shortest_solution();
+        let fewest_words = best.as_ref().map(|solution| solution.words.len());
+
+        let counted_words = fewest_words.map_or(0, |fewest| (fewest + 1).min(max_words));
+        let solutions_by_length = if counted_words > 0 {
+            let counts = Solver::from_index(index, 1)
+                .with_word_range(Solver::DEFAULT_MIN_WORDS, counted_words)
+                .count_solutions();
+            counts.iter().collect()
+        } else {
+            Vec::new()
+        };
+
+        let inputs = DifficultyInputs {
+            fewest_words,
+            best_frequency: best.and_then(|solution| solution.words.iter().map(|w| w.frequency).min()),
+            solutions_by_length,
+            playable_words: index.len(),
+        };
+        Self::from_inputs(inputs)
+    }
+
+    /// Rate a board from what's already known about it.
+    pub fn from_inputs(inputs: DifficultyInputs) -> Self {
+        let shortest_solutions = inputs
+            .fewest_words
+            .and_then(|fewest| inputs.solutions_by_length.iter().find(|&&(words, _)| words == fewest))
+            .map_or(0, |&(_, count)| count);
+
+        let factors = vec![
+            DifficultyFactor {
+                name: "fewest words",
+                // 1 word is easiest, 4 or more (or none at all) hardest
+                value: inputs.fewest_words.map_or(1.0, |words| scale(words as f64, 1.0, 4.0)),
+                weight: 0.3,
+            },
+            DifficultyFactor {
+                name: "shortest solutions",
+                // Only one way to do it is hard; thousands are easy
+                value: 1.0 - scale((shortest_solutions.max(1) as f64).log10(), 0.0, 4.0),
+                weight: 0.25,
+            },
+            DifficultyFactor {
+                name: "rarest word",
+                // Frequencies of 24 and up are everyday words, and 8 and below are obscure
+                value: inputs.best_frequency.map_or(1.0, |frequency| 1.0 - scale(frequency.into(), 8.0, 24.0)),
+                weight: 0.3,
+            },
+            DifficultyFactor {
+                name: "playable words",
+                // A board with few playable words gives a player less to work with
+                value: 1.0 - scale((inputs.playable_words.max(1) as f64).log10(), 200f64.log10(), 5000f64.log10()),
+                weight: 0.15,
+            },
+        ];
+
+        let rating = 100.0 * factors.iter().map(|factor| factor.value * factor.weight).sum::<f64>();
+        let difficulty = if rating < MEDIUM_FROM {
+            Difficulty::Easy
+        } else if rating < HARD_FROM {
+            Difficulty::Medium
+        } else {
+            Difficulty::Hard
+        };
+
+        DifficultyRating {
+            rating,
+            difficulty,
+            inputs,
+            factors,
+        }
+    }
+}
+
+/// Where `value` falls between `low` (0) and `high` (1), clamped to that range.
+fn scale(value: f64, low: f64, high: f64) -> f64 {
+    ((value - low) / (high - low)).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(fewest_words: Option<usize>, shortest: u64, best_frequency: Option<i8>, playable_words: usize) -> DifficultyInputs {
+        DifficultyInputs {
+            fewest_words,
+            solutions_by_length: fewest_words.map(|words| vec![(words, shortest)]).unwrap_or_default(),
+            best_frequency,
+            playable_words,
+        }
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(scale(5.0, 0.0, 10.0), 0.5);
+        assert_eq!(scale(-1.0, 0.0, 10.0), 0.0);
+        assert_eq!(scale(11.0, 0.0, 10.0), 1.0);
+    }
+
+    #[test]
+    fn test_ratings() {
+        let easy = DifficultyRating::from_inputs(inputs(Some(2), 10_000, Some(24), 5000));
+        assert_eq!(easy.difficulty, Difficulty::Easy);
+        assert!((easy.rating - 10.0).abs() < 1e-9, "{}", easy.rating);
+
+        let medium = DifficultyRating::from_inputs(inputs(Some(2), 30, Some(19), 1300));
+        assert_eq!(medium.difficulty, Difficulty::Medium);
+
+        let hard = DifficultyRating::from_inputs(inputs(Some(3), 1, Some(9), 300));
+        assert_eq!(hard.difficulty, Difficulty::Hard);
+
+        let unsolvable = DifficultyRating::from_inputs(inputs(None, 0, None, 200));
+        assert!((unsolvable.rating - 100.0).abs() < 1e-9, "{}", unsolvable.rating);
+        assert_eq!(unsolvable.difficulty, Difficulty::Hard);
+    }
+}
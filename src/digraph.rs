@@ -0,0 +1,157 @@
+use std::fmt;
+
+/// Number of lowercase ASCII letters, the only letters a board can have.
+pub(crate) const ALPHABET: usize = 26;
+
+/// Number of distinct lowercase digraphs, "aa" to "zz".
+pub const DIGRAPH_COUNT: u16 = (ALPHABET * ALPHABET) as u16;
+
+/// Stands for every pair involving something other than a lowercase letter. Boards never contain it,
+/// so words containing such pairs are never playable.
+const OTHER: u16 = DIGRAPH_COUNT;
+
+const WORDS: usize = (DIGRAPH_COUNT as usize + 1).div_ceil(64);
+
+/// A lowercase ASCII letter's position in the alphabet, from a = 0.
+pub(crate) fn letter_index(ch: char) -> usize {
+    (ch as u8 - b'a') as usize
+}
+
+/// The index of the digraph `ab` in a `DigraphSet`, or `None` unless both are lowercase ASCII letters.
+pub fn pair_index(a: char, b: char) -> Option<u16> {
+    let letter = |ch: char| ch.is_ascii_lowercase().then(|| letter_index(ch) as u16);
+    Some(letter(a)? * ALPHABET as u16 + letter(b)?)
+}
+
+fn pair_chars(index: u16) -> (char, char) {
+    let letter = |i: u16| (b'a' + i as u8) as char;
+    (letter(index / ALPHABET as u16), letter(index % ALPHABET as u16))
+}
+
+/// A set of digraphs (ordered letter pairs), as one bit for each of the 676 pairs "aa" to "zz".
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DigraphSet {
+    bits: [u64; WORDS],
+}
+
+impl DigraphSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The digraphs (consecutive letter pairs) of a word.
+    pub fn from_word(word: &str) -> Self {
+        let mut digraphs = Self::new();
+        let mut chars = word.chars();
+        if let Some(mut previous) = chars.next() {
+            for ch in chars {
+                digraphs.insert_index(pair_index(previous, ch).unwrap_or(OTHER));
+                previous = ch;
+            }
+        }
+        digraphs
+    }
+
+    pub fn insert(&mut self, a: char, b: char) {
+        self.insert_index(pair_index(a, b).unwrap_or(OTHER));
+    }
+
+    pub fn insert_index(&mut self, index: u16) {
+        self.bits[index as usize / 64] |= 1 << (index % 64);
+    }
+
+    pub fn contains_index(&self, index: u16) -> bool {
+        self.bits[index as usize / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Whether the set contains a digraph like "ab". Anything but two lowercase letters is never contained.
+    pub fn contains(&self, digraph: &str) -> bool {
+        let mut chars = digraph.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(a), Some(b), None) => pair_index(a, b).is_some_and(|index| self.contains_index(index)),
+            _ => false,
+        }
+    }
+
+    /// Whether every digraph in this set is also in `other`.
+    pub fn is_subset(&self, other: &DigraphSet) -> bool {
+        self.bits.iter().zip(&other.bits).all(|(a, b)| a & !b == 0)
+    }
+
+    pub fn union(&self, other: &DigraphSet) -> DigraphSet {
+        let mut bits = self.bits;
+        bits.iter_mut().zip(&other.bits).for_each(|(a, b)| *a |= b);
+        DigraphSet { bits }
+    }
+
+    pub fn intersection(&self, other: &DigraphSet) -> DigraphSet {
+        let mut bits = self.bits;
+        bits.iter_mut().zip(&other.bits).for_each(|(a, b)| *a &= b);
+        DigraphSet { bits }
+    }
+
+    /// The indices of the lowercase digraphs in the set, in alphabetical order.
+    pub fn indices(&self) -> impl Iterator<Item = u16> + '_ {
+        (0..DIGRAPH_COUNT).filter(|&index| self.contains_index(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// The lowercase digraphs in the set as strings, in alphabetical order. Handy for debugging.
+    pub fn to_strings(&self) -> Vec<String> {
+        self.indices()
+            .map(|index| {
+                let (a, b) = pair_chars(index);
+                format!("{}{}", a, b)
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for DigraphSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.to_strings()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_word() {
+        let digraphs = DigraphSet::from_word("pirate");
+
+        assert_eq!(digraphs.to_strings(), vec!["at", "ir", "pi", "ra", "te"]);
+        assert!(digraphs.contains("pi"));
+        assert!(!digraphs.contains("ip"));
+        assert_eq!(digraphs.len(), 5);
+    }
+
+    #[test]
+    fn test_other_characters_are_never_playable() {
+        let board = DigraphSet::from_word("abcdefghijklmnopqrstuvwxyz");
+        assert!(DigraphSet::from_word("abc").is_subset(&board));
+        assert!(!DigraphSet::from_word("aBc").is_subset(&board));
+        assert!(!DigraphSet::from_word("AB").contains("AB"));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let ab = DigraphSet::from_word("ab");
+        let abc = DigraphSet::from_word("abc");
+
+        assert!(ab.is_subset(&abc));
+        assert!(!abc.is_subset(&ab));
+        assert_eq!(ab.union(&abc), abc);
+        assert_eq!(ab.intersection(&abc), ab);
+        assert!(DigraphSet::new().is_empty());
+        assert_eq!(pair_index('z', 'z'), Some(DIGRAPH_COUNT - 1));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::board::{Side, MIN_SIDES};
+use std::fmt;
+use std::io;
+use std::str::Utf8Error;
+
+/// Everything that can go wrong loading a board or a dictionary.
+#[derive(Debug)]
+pub enum Error {
+    /// The board has fewer than `MIN_SIDES` sides.
+    WrongSideCount { found: usize },
+    EmptySide { side: Side },
+    /// Only lowercase ASCII letters may appear on a board.
+    InvalidChar { ch: char, side: Side },
+    /// A letter appears twice, either on two sides or twice on the same one.
+    DuplicateLetter { ch: char, sides: (Side, Side) },
+    /// Asked to change a letter which isn't on the board.
+    LetterNotOnBoard { ch: char },
+    /// A word played which isn't in the dictionary, or can't be played on the board.
+    UnplayableWord { word: String },
+    /// Words played one after the other, where `to` doesn't start with the letter `from` ends with.
+    BrokenChain { from: String, to: String },
+    /// A search cursor which can't be read, or doesn't fit the search it's meant to carry on.
+    InvalidCursor { cursor: String },
+    /// A dictionary line which isn't a word followed by its frequency. Lines are numbered from 1.
+    MalformedDictionaryLine { line: usize, content: String },
+    /// A word which can't be written to a packed dictionary, which only holds words of 1 to 31 lowercase
+    /// letters, with frequencies from 0 to 31.
+    UnencodableWord { word: String },
+    /// A dictionary with more words than a packed dictionary's 32-bit word count can hold.
+    TooManyWords { count: usize },
+    /// A packed dictionary written by a newer version of this library.
+    UnsupportedDictionaryVersion { version: u8 },
+    CorruptDictionary { reason: &'static str },
+    /// `Generator` limits which no board could meet, such as more vowels than its sides have room for.
+    InvalidGeneratorConfig { reason: String },
+    InvalidUtf8(Utf8Error),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::WrongSideCount { found } => {
+                write!(f, "Game must contain at least {} sides, found {}", MIN_SIDES, found)
+            }
+            Error::EmptySide { side } => write!(f, "Empty sides are not allowed, but {} is empty", side),
+            Error::InvalidChar { ch, side } => write!(
+                f,
+                "Invalid character '{}' on {}. Only lowercase ASCII letters are allowed",
+                ch, side
+            ),
+            Error::DuplicateLetter { ch, sides: (first, second) } if first == second => {
+                write!(f, "Duplicate letter '{}' found on {}", ch, first)
+            }
+            Error::DuplicateLetter { ch, sides: (first, second) } => {
+                write!(f, "Duplicate letter '{}' found on {} and {}", ch, first, second)
+            }
+            Error::LetterNotOnBoard { ch } => write!(f, "The letter '{}' is not on the board", ch),
+            Error::UnplayableWord { word } => write!(f, "'{}' can't be played on this board", word),
+            Error::BrokenChain { from, to } => {
+                write!(f, "'{}' does not start with the last letter of '{}'", to, from)
+            }
+            Error::InvalidCursor { cursor } => write!(f, "Can't carry on a search from '{}'", cursor),
+            Error::MalformedDictionaryLine { line, content } => {
+                write!(f, "Invalid format on line {}: {}", line, content)
+            }
+            Error::UnencodableWord { word } => write!(f, "Can't pack the word '{}' into a binary dictionary", word),
+            Error::TooManyWords { count } => {
+                write!(f, "Can't pack {} words into a binary dictionary, which holds at most {}", count, u32::MAX)
+            }
+            Error::UnsupportedDictionaryVersion { version } => {
+                write!(f, "Unsupported binary dictionary version {}", version)
+            }
+            Error::CorruptDictionary { reason } => write!(f, "Corrupt binary dictionary: {}", reason),
+            Error::InvalidGeneratorConfig { reason } => write!(f, "Can't generate a board: {}", reason),
+            Error::InvalidUtf8(e) => write!(f, "Invalid UTF-8 data: {}", e),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidUtf8(e) => Some(e),
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(e: Utf8Error) -> Self {
+        Error::InvalidUtf8(e)
+    }
+}
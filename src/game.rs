@@ -0,0 +1,160 @@
+use crate::answer::{AnswerCheck, AnswerProblem};
+use crate::board::Board;
+use crate::dictionary::Dictionary;
+use crate::hint::Hints;
+use crate::solver::{Solution, Solver};
+
+/// A game in progress: the words played so far on a board, one at a time.
+pub struct Game<'a> {
+    board: Board,
+    dictionary: &'a Dictionary,
+    solver: Solver,
+    words: Vec<String>,
+}
+
+impl<'a> Game<'a> {
+    pub fn new(board: Board, dictionary: &'a Dictionary) -> Self {
+        let solver = Solver::new(board.clone(), dictionary, 1);
+        Game {
+            board,
+            dictionary,
+            solver,
+            words: Vec::new(),
+        }
+    }
+
+    /// Only look for solutions of up to `max_words` words, for the best solution, or words still to play, for hints.
+    pub fn with_max_words(mut self, max_words: usize) -> Self {
+        self.solver = self.solver.with_word_range(Solver::DEFAULT_MIN_WORDS, max_words);
+        self
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Play the next word. It has to be in the dictionary, follow the rules of the board, and start with the
+    /// letter the last word ended with; if it doesn't, nothing is played and the problems are returned.
+    pub fn play(&mut self, word: &str) -> Result<(), Vec<AnswerProblem>> {
+        let mut words: Vec<&str> = self.words.iter().map(String::as_str).collect();
+        words.push(word);
+
+        // Everything played so far was fine, so any problems are with the new word
+        let problems: Vec<AnswerProblem> = self
+            .board
+            .check_answer(&words, self.dictionary)
+            .problems
+            .into_iter()
+            .filter(|problem| !matches!(problem, AnswerProblem::Uncovered { .. }))
+            .collect();
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        self.words.push(word.to_string());
+        Ok(())
+    }
+
+    /// Take back the last word played, if there is one.
+    pub fn undo(&mut self) -> Option<String> {
+        self.words.pop()
+    }
+
+    /// The letter the next word has to start with, unless it's the first word.
+    pub fn next_letter(&self) -> Option<char> {
+        self.words.last().and_then(|word| word.chars().last())
+    }
+
+    pub fn is_covered(&self, letter: char) -> bool {
+        self.words.iter().any(|word| word.contains(letter))
+    }
+
+    /// The letters on the board which no word has used yet, in order around the board.
+    pub fn uncovered(&self) -> Vec<char> {
+        self.board
+            .sides
+            .iter()
+            .flat_map(|side| side.chars())
+            .filter(|&letter| !self.is_covered(letter))
+            .collect()
+    }
+
+    pub fn is_won(&self) -> bool {
+        !self.words.is_empty() && self.uncovered().is_empty()
+    }
+
+    /// The verdict on the words played so far, as if they were a finished answer.
+    pub fn check(&self) -> AnswerCheck {
+        let words: Vec<&str> = self.words.iter().map(String::as_str).collect();
+        self.board.check_answer(&words, self.dictionary)
+    }
+
+    /// Hints towards the best way to finish from here, or `None` if there's no way to finish.
+    pub fn hints(&self) -> Option<Hints> {
+        let words: Vec<&str> = self.words.iter().map(String::as_str).collect();
+        let completion = self.solver.best_completion(&words).ok()??;
+        Some(Hints::from_solution(&completion))
+    }
+
+    /// The solver's best solution to the board from scratch: the best scoring of those with the fewest words.
+    pub fn best_solution(&self) -> Option<Solution> {
+        self.solver.best_shortest_solution()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hint::{Hint, HintLevel};
+    use crate::test_fixtures::{forklift_board, forklift_dictionary};
+
+    #[test]
+    fn test_play_to_win() {
+        let dictionary = forklift_dictionary();
+        let mut game = Game::new(forklift_board(), &dictionary);
+
+        game.play("forklift").unwrap();
+        assert_eq!(game.next_letter(), Some('t'));
+        assert_eq!(game.uncovered(), vec!['y', 'a', 'g', 'w', 'n']);
+        assert!(!game.is_won());
+
+        game.play("twangy").unwrap();
+        assert!(game.is_won());
+        assert!(game.check().is_valid());
+    }
+
+    #[test]
+    fn test_rejected_words() {
+        let dictionary = forklift_dictionary();
+        let mut game = Game::new(forklift_board(), &dictionary);
+        game.play("fork").unwrap();
+
+        assert!(matches!(game.play("tangy").unwrap_err()[..], [AnswerProblem::ChainBreak { .. }]));
+        assert!(matches!(game.play("kit").unwrap_err()[..], [AnswerProblem::NotInDictionary { .. }, ..]));
+        assert_eq!(game.words(), ["fork"]);
+    }
+
+    #[test]
+    fn test_undo_and_hints() {
+        let dictionary = forklift_dictionary();
+        let mut game = Game::new(forklift_board(), &dictionary);
+        game.play("fork").unwrap();
+        game.play("kilt").unwrap();
+
+        let hints = game.hints().unwrap();
+        assert_eq!(hints.hint(HintLevel::WordCount), Hint::WordCount { words: 1 });
+        assert_eq!(hints.hint(HintLevel::FirstWord), Hint::FirstWord { word: "twangy".to_string() });
+
+        assert_eq!(game.undo(), Some("kilt".to_string()));
+        assert_eq!(game.undo(), Some("fork".to_string()));
+        assert_eq!(game.undo(), None);
+
+        let hints = game.hints().unwrap();
+        assert_eq!(hints.hint(HintLevel::FirstWord), Hint::FirstWord { word: "forklift".to_string() });
+        assert_eq!(game.best_solution().unwrap().to_string(), "forklift-twangy");
+    }
+}
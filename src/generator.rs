@@ -0,0 +1,304 @@
+use crate::board::{Board, MIN_SIDES};
+use crate::dictionary::{Dictionary, Word};
+use crate::digraph::{letter_index, ALPHABET};
+use crate::error::Error;
+use crate::solver::{Solution, Solver};
+use std::ops::RangeInclusive;
+
+const VOWELS: &str = "aeiou";
+/// Letters worth 5 or more in Scrabble.
+const RARE_LETTERS: &str = "jkqxz";
+
+/// How many pairs of words `Generator::generate` tries before giving up.
+const PAIR_ATTEMPTS: usize = 10_000;
+/// How many ways to deal a pair's letters onto the sides are tried before moving on to another pair.
+const ARRANGE_ATTEMPTS: usize = 100;
+
+/// A generated board, and the best of its 2-word solutions using only common words.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    pub board: Board,
+    pub answer: Solution,
+}
+
+/// Makes up boards which are guaranteed to have a 2-word solution of common words.
+///
+/// Rather than dealing out letters and hoping, this starts from the answer: two common words which chain
+/// together and use exactly as many distinct letters as the board has. Their letters are then dealt onto the
+/// sides until neither word has two letters in a row on the same side, and the solver confirms the result.
+pub struct Generator<'a> {
+    dictionary: &'a Dictionary,
+    rng: SplitMix64,
+    sides: usize,
+    letters_per_side: usize,
+    vowels: RangeInclusive<usize>,
+    max_vowels_per_side: usize,
+    max_rare_letters: usize,
+    min_frequency: i8,
+}
+
+impl<'a> Generator<'a> {
+    pub const DEFAULT_SIDES: usize = 4;
+    pub const DEFAULT_LETTERS_PER_SIDE: usize = 3;
+    pub const DEFAULT_VOWELS: RangeInclusive<usize> = 3..=5;
+    pub const DEFAULT_MAX_VOWELS_PER_SIDE: usize = 2;
+    pub const DEFAULT_MAX_RARE_LETTERS: usize = 1;
+    /// About as common as "jukebox".
+    pub const DEFAULT_MIN_FREQUENCY: i8 = 18;
+
+    /// A generator for the usual 12 letter boards. The same seed always generates the same boards.
+    pub fn new(dictionary: &'a Dictionary, seed: u64) -> Self {
+        Generator {
+            dictionary,
+            rng: SplitMix64(seed),
+            sides: Self::DEFAULT_SIDES,
+            letters_per_side: Self::DEFAULT_LETTERS_PER_SIDE,
+            vowels: Self::DEFAULT_VOWELS,
+            max_vowels_per_side: Self::DEFAULT_MAX_VOWELS_PER_SIDE,
+            max_rare_letters: Self::DEFAULT_MAX_RARE_LETTERS,
+            min_frequency: Self::DEFAULT_MIN_FREQUENCY,
+        }
+    }
+
+    /// Generate boards of `sides` sides with `letters_per_side` letters each.
+    pub fn with_shape(mut self, sides: usize, letters_per_side: usize) -> Self {
+        self.sides = sides;
+        self.letters_per_side = letters_per_side;
+        self
+    }
+
+    /// How many vowels the board should have, and how many of them one side may hold. Y isn't a vowel here.
+    pub fn with_vowels(mut self, vowels: RangeInclusive<usize>, max_per_side: usize) -> Self {
+        self.vowels = vowels;
+        self.max_vowels_per_side = max_per_side;
+        self
+    }
+
+    /// How many of the rare letters j, k, q, x and z the board may have.
+    pub fn with_max_rare_letters(mut self, max_rare_letters: usize) -> Self {
+        self.max_rare_letters = max_rare_letters;
+        self
+    }
+
+    /// How common the words of the answer must be, on the dictionary's frequency scale.
+    pub fn with_min_frequency(mut self, min_frequency: i8) -> Self {
+        self.min_frequency = min_frequency;
+        self
+    }
+
+    /// Make up a new board, or `None` if the constraints are too tight to find one. Limits which no board
+    /// could meet, such as more vowels than the sides have room for, are an `InvalidGeneratorConfig` error.
+    pub fn generate(&mut self) -> Result<Option<Puzzle>, Error> {
+        self.check_limits()?;
+        Ok(self.find_puzzle())
+    }
+
+    /// Make sure some board could meet the limits: its letters have to fit in the alphabet, and its sides
+    /// have to have room for the vowels asked for.
+    fn check_limits(&self) -> Result<(), Error> {
+        let invalid = |reason| Err(Error::InvalidGeneratorConfig { reason });
+        let (sides, letters_per_side) = (self.sides, self.letters_per_side);
+        let (min, max) = (*self.vowels.start(), *self.vowels.end());
+        let most_per_side = letters_per_side.min(self.max_vowels_per_side);
+
+        if sides < MIN_SIDES {
+            invalid(format!("a board needs at least {} sides, not {}", MIN_SIDES, sides))
+        } else if letters_per_side == 0 || sides * letters_per_side > ALPHABET {
+            invalid(format!("can't make a board of {} sides with {} letters each", sides, letters_per_side))
+        } else if min > max {
+            invalid(format!("can't have at least {} vowels but no more than {}", min, max))
+        } else if min > VOWELS.len() {
+            invalid(format!("there are only {} vowels, not {}", VOWELS.len(), min))
+        } else if min > sides * most_per_side {
+            invalid(format!("{} sides with at most {} vowels each can't hold {} vowels", sides, most_per_side, min))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn find_puzzle(&mut self) -> Option<Puzzle> {
+        let letters = self.sides * self.letters_per_side;
+        let common: Vec<(&Word, u32)> = self
+            .dictionary
+            .words
+            .iter()
+            .filter(|word| word.frequency >= self.min_frequency)
+            .filter_map(|word| Some((word, letter_mask(&word.word)?)))
+            .filter(|&(_, mask)| mask.count_ones() as usize <= letters && self.letters_allowed(mask))
+            .collect();
+        if common.is_empty() {
+            return None;
+        }
+
+        let mut by_first_letter: Vec<Vec<usize>> = vec![Vec::new(); ALPHABET];
+        for (i, (word, _)) in common.iter().enumerate() {
+            let first = word.word.chars().next().expect("common words aren't empty");
+            by_first_letter[letter_index(first)].push(i);
+        }
+        let common_words = Dictionary::from_words(common.iter().map(|&(word, _)| word.clone()).collect());
+
+        for _ in 0..PAIR_ATTEMPTS {
+            let (first, first_mask) = common[self.rng.below(common.len())];
+
+            // The second word has to pick up where the first left off, and between them they must use
+            // every letter of the board, without either one managing it alone
+            let last = first.word.chars().last().expect("common words aren't empty");
+            let seconds: Vec<usize> = by_first_letter[letter_index(last)]
+                .iter()
+                .copied()
+                .filter(|&i| {
+                    let mask = first_mask | common[i].1;
+                    mask.count_ones() as usize == letters
+                        && mask != first_mask
+                        && mask != common[i].1
+                        && self.letters_allowed(mask)
+                        && self.vowels.contains(&count_in(mask, VOWELS))
+                })
+                .collect();
+            if seconds.is_empty() {
+                continue;
+            }
+            let (second, second_mask) = common[seconds[self.rng.below(seconds.len())]];
+
+            let Some(board) = self.arrange(first_mask | second_mask, &[first, second]) else {
+                continue;
+            };
+
+            // The pair we started from is a solution, but there may be a better one
+            let solver = Solver::new(board.clone(), &common_words, 1).with_word_range(2, 2);
+            if let Some(answer) = solver.solve_best(None).into_iter().next() {
+                return Some(Puzzle { board, answer });
+            }
+        }
+
+        None
+    }
+
+    /// Whether a set of letters stays within the limits on vowels and rare letters. Used on single words,
+    /// which can't have more of either than the whole board is allowed.
+    fn letters_allowed(&self, mask: u32) -> bool {
+        count_in(mask, VOWELS) <= *self.vowels.end() && count_in(mask, RARE_LETTERS) <= self.max_rare_letters
+    }
+
+    /// Deal the letters onto the sides at random until the words can all be played.
+    fn arrange(&mut self, mask: u32, words: &[&Word]) -> Option<Board> {
+        let mut letters: Vec<char> = ('a'..='z').filter(|&ch| mask & (1 << letter_index(ch)) != 0).collect();
+
+        for _ in 0..ARRANGE_ATTEMPTS {
+            for i in (1..letters.len()).rev() {
+                letters.swap(i, self.rng.below(i + 1));
+            }
+            let sides: Vec<String> = letters
+                .chunks(self.letters_per_side)
+                .map(|side| side.iter().collect())
+                .collect();
+            let too_many_vowels = sides
+                .iter()
+                .any(|side| side.chars().filter(|&ch| VOWELS.contains(ch)).count() > self.max_vowels_per_side);
+            if too_many_vowels {
+                continue;
+            }
+
+            let board = Board::from_sides(sides).expect("distinct lowercase letters make a valid board");
+            if words.iter().all(|word| word.digraphs.is_subset(&board.digraphs)) {
+                return Some(board);
+            }
+        }
+
+        None
+    }
+}
+
+/// The letters of a word as bits, a = 1. `None` unless the word is all lowercase letters, with no letter twice
+/// in a row, since those could never be played.
+fn letter_mask(word: &str) -> Option<u32> {
+    let mut mask = 0;
+    let mut previous = None;
+    for ch in word.chars() {
+        if !ch.is_ascii_lowercase() || previous == Some(ch) {
+            return None;
+        }
+        mask |= 1 << letter_index(ch);
+        previous = Some(ch);
+    }
+    (mask != 0).then_some(mask)
+}
+
+fn count_in(mask: u32, letters: &str) -> usize {
+    letters.chars().filter(|&ch| mask & (1 << letter_index(ch)) != 0).count()
+}
+
+/// A small, seedable random number generator, so that a seed always generates the same boards.
+/// See https://prng.di.unimi.it/splitmix64.c
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to, but not including, `n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letter_mask() {
+        assert_eq!(letter_mask("cab"), Some(0b111));
+        assert_eq!(letter_mask("ball"), None);
+        assert_eq!(letter_mask("Cab"), None);
+        assert_eq!(count_in(letter_mask("queue").unwrap(), VOWELS), 2);
+    }
+
+    fn limits_error(generator: Generator) -> String {
+        match generator.check_limits() {
+            Err(Error::InvalidGeneratorConfig { reason }) => reason,
+            other => panic!("Expected the limits to be rejected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_vowel_range_backwards() {
+        let dictionary = Dictionary::from_words(Vec::new());
+        let generator = Generator::new(&dictionary, 1).with_vowels(RangeInclusive::new(9, 2), 2);
+        assert_eq!(limits_error(generator), "can't have at least 9 vowels but no more than 2");
+    }
+
+    #[test]
+    fn test_vowels_per_side_too_few() {
+        let dictionary = Dictionary::from_words(Vec::new());
+        let generator = Generator::new(&dictionary, 1).with_vowels(5..=5, 1);
+        assert_eq!(limits_error(generator), "4 sides with at most 1 vowels each can't hold 5 vowels");
+
+        // Limits are only checked once they're all set, so the order they're given in doesn't matter
+        let generator = Generator::new(&dictionary, 1).with_shape(3, 1).with_vowels(1..=1, 1).with_shape(3, 3);
+        assert!(generator.check_limits().is_ok());
+    }
+
+    #[test]
+    fn test_bad_shapes() {
+        let dictionary = Dictionary::from_words(Vec::new());
+        let generator = Generator::new(&dictionary, 1).with_shape(2, 3);
+        assert_eq!(limits_error(generator), "a board needs at least 3 sides, not 2");
+        let generator = Generator::new(&dictionary, 1).with_shape(9, 3);
+        assert_eq!(limits_error(generator), "can't make a board of 9 sides with 3 letters each");
+    }
+
+    #[test]
+    fn test_rng_is_repeatable() {
+        let (mut a, mut b) = (SplitMix64(42), SplitMix64(42));
+        for _ in 0..5 {
+            assert_eq!(a.next(), b.next());
+        }
+        assert!((0..100).all(|_| a.below(12) < 12));
+    }
+}
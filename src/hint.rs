@@ -0,0 +1,154 @@
+use crate::solver::{Solution, Solver};
+use serde::Serialize;
+use std::fmt;
+
+/// How much a hint gives away, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HintLevel {
+    WordCount,
+    FirstLetter,
+    Length,
+    Pattern,
+    FirstWord,
+}
+
+impl HintLevel {
+    pub const ALL: [HintLevel; 5] = [
+        HintLevel::WordCount,
+        HintLevel::FirstLetter,
+        HintLevel::Length,
+        HintLevel::Pattern,
+        HintLevel::FirstWord,
+    ];
+
+    /// Levels are numbered from 1, the vaguest, to 5, which gives away the first word.
+    pub fn from_number(number: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(number).checked_sub(1)?).copied()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Hint {
+    WordCount { words: usize },
+    FirstLetter { letter: char },
+    Length { letters: usize },
+    /// The first word with every other letter blanked out, like "f_r_l_f_".
+    Pattern { pattern: String },
+    FirstWord { word: String },
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::WordCount { words } => write!(f, "This board can be solved in {} words", words),
+            Hint::FirstLetter { letter } => write!(f, "The first word starts with '{}'", letter),
+            Hint::Length { letters } => write!(f, "The first word has {} letters", letters),
+            Hint::Pattern { pattern } => write!(f, "The first word looks like {}", pattern),
+            Hint::FirstWord { word } => write!(f, "The first word is {}", word),
+        }
+    }
+}
+
+/// Hints towards the best of a board's shortest solutions, each giving away a little more than the last.
+#[derive(Debug, Clone)]
+pub struct Hints {
+    word_count: usize,
+    first_word: String,
+}
+
+impl Hints {
+    /// Hints for whatever the solver can find, or `None` if it finds no solutions.
+    pub fn new(solver: &Solver) -> Option<Self> {
+        Some(Self::from_solution(&solver.best_shortest_solution()?))
+    }
+
+    /// Hints towards a particular solution, which mustn't be empty.
+    pub fn from_solution(solution: &Solution) -> Self {
+        Hints {
+            word_count: solution.words.len(),
+            first_word: solution.words[0].word.clone(),
+        }
+    }
+
+    pub fn hint(&self, level: HintLevel) -> Hint {
+        match level {
+            HintLevel::WordCount => Hint::WordCount { words: self.word_count },
+            HintLevel::FirstLetter => Hint::FirstLetter {
+                letter: self.first_word.chars().next().unwrap_or_default(),
+            },
+            HintLevel::Length => Hint::Length {
+                letters: self.first_word.chars().count(),
+            },
+            HintLevel::Pattern => Hint::Pattern {
+                pattern: mask(&self.first_word),
+            },
+            HintLevel::FirstWord => Hint::FirstWord {
+                word: self.first_word.clone(),
+            },
+        }
+    }
+
+    /// Every hint from the vaguest up to `level`.
+    pub fn up_to(&self, level: HintLevel) -> Vec<Hint> {
+        HintLevel::ALL.iter().filter(|&&l| l <= level).map(|&l| self.hint(l)).collect()
+    }
+}
+
+/// Blank out every other letter, keeping the first.
+fn mask(word: &str) -> String {
+    word.chars()
+        .enumerate()
+        .map(|(i, ch)| if i % 2 == 0 { ch } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Board;
+    use crate::dictionary::{Dictionary, Word};
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(mask("forklift"), "f_r_l_f_");
+        assert_eq!(mask("fox"), "f_x");
+        assert_eq!(mask(""), "");
+    }
+
+    #[test]
+    fn test_from_number() {
+        assert_eq!(HintLevel::from_number(1), Some(HintLevel::WordCount));
+        assert_eq!(HintLevel::from_number(5), Some(HintLevel::FirstWord));
+        assert_eq!(HintLevel::from_number(0), None);
+        assert_eq!(HintLevel::from_number(6), None);
+    }
+
+    #[test]
+    fn test_hints() {
+        let board = Board::from_sides(["vyq", "fig", "ote", "xlu"].iter().map(|s| s.to_string()).collect()).unwrap();
+        // foxglove-equity is the only 2-word solution
+        let dictionary = Dictionary::from_words(vec![
+            Word::new("foxglove".to_string(), 10),
+            Word::new("equity".to_string(), 20),
+            Word::new("fox".to_string(), 25),
+        ]);
+        let hints = Hints::new(&Solver::new(board, &dictionary, 1)).unwrap();
+
+        assert_eq!(
+            hints.up_to(HintLevel::FirstWord),
+            vec![
+                Hint::WordCount { words: 2 },
+                Hint::FirstLetter { letter: 'f' },
+                Hint::Length { letters: 8 },
+                Hint::Pattern {
+                    pattern: "f_x_l_v_".to_string()
+                },
+                Hint::FirstWord {
+                    word: "foxglove".to_string()
+                },
+            ]
+        );
+        assert_eq!(hints.up_to(HintLevel::WordCount).len(), 1);
+    }
+}
@@ -1,6 +1,20 @@
+pub mod answer;
 pub mod board;
 pub mod dictionary;
+pub mod difficulty;
+pub mod digraph;
+pub mod error;
+pub mod game;
+pub mod generator;
+pub mod hint;
+mod packed;
+pub mod playable;
+pub mod scorer;
 pub mod solver;
+#[cfg(test)]
+mod test_fixtures;
+
+pub use error::Error;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
@@ -1,23 +1,254 @@
-use letter_bounced::{board::Board, solver::Solver, dictionary::Dictionary}; // using our library!
-use clap::Parser;
+use letter_bounced::{board::Board, solver::{SearchCursor, Solution, Solver}, dictionary::Dictionary}; // using our library!
+use letter_bounced::answer::AnswerProblem;
+use letter_bounced::difficulty::DifficultyRating;
+use letter_bounced::digraph::DigraphSet;
+use letter_bounced::game::Game;
+use letter_bounced::generator::Generator;
+use letter_bounced::hint::{HintLevel, Hints};
+use letter_bounced::playable::PlayableIndex;
+use letter_bounced::scorer::{AverageFrequency, FewestLetters, LongestWord, MinFrequency, MostObscure, SolutionScorer};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use log::debug;
-use std::{collections::HashSet, path::Path};
+use std::io;
+use std::path::Path;
+use std::process::exit;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+mod output;
+mod play;
+use output::{Format, Printer, Summary};
 
 #[derive(Parser)]
 #[command(name = "letter-bounced")]
 #[command(about = "A Rust word game application for Letter Boxed puzzles")]
-struct Args {
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// With no subcommand, solve the board
+    #[command(flatten)]
+    solve: SolveArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a board (the default)
+    Solve(SolveArgs),
+    /// Check whether a chain of words solves a board
+    Check(CheckArgs),
+    /// Get a hint, without giving the whole answer away
+    Hint(HintArgs),
+    /// Play the board in the terminal, a word at a time
+    Play(PlayArgs),
+    /// Describe a board and the words playable on it
+    Stats(StatsArgs),
+    /// Rate how hard a board is: easy, medium or hard
+    Difficulty(DifficultyArgs),
+    /// Make up a new board with a 2-word answer of common words
+    Generate(GenerateArgs),
+}
+
+/// Where to find the board, shared by every subcommand which needs one.
+#[derive(Args)]
+struct BoardArgs {
     /// Game specification as comma-separated sides (e.g., "ABC,DEF,GHI,JKL")
     board_spec: Option<String>,
 
     #[arg(long)]
     board: Option<String>,
+}
 
+#[derive(Args)]
+struct DictionaryArgs {
     #[arg(long, default_value = "data/dictionary.txt")]
     dictionary: String,
+}
+
+/// How long the solutions considered may be, shared by the subcommands which look for them to help
+/// or rate a board.
+#[derive(Args)]
+struct WordRangeArgs {
+    /// Longest solutions to consider, in words
+    #[arg(long, default_value_t = Solver::DEFAULT_MAX_WORDS as u8, value_parser = clap::value_parser!(u8).range(1..))]
+    max_words: u8,
+}
+
+#[derive(Args)]
+struct SolveArgs {
+    #[command(flatten)]
+    board: BoardArgs,
+
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
 
     #[arg(long, default_value_t = 500u16)]
     max_solutions: u16,
+
+    /// Shortest solutions to look for, in words
+    #[arg(long, default_value_t = Solver::DEFAULT_MIN_WORDS as u8, value_parser = clap::value_parser!(u8).range(1..))]
+    min_words: u8,
+
+    /// Longest solutions to look for, in words
+    #[arg(long, default_value_t = Solver::DEFAULT_MAX_WORDS as u8, value_parser = clap::value_parser!(u8).range(1..))]
+    max_words: u8,
+
+    /// How to rank solutions
+    #[arg(long, value_enum, default_value_t = RankBy::Frequency)]
+    rank_by: RankBy,
+
+    /// Print solutions as soon as they are found, in search order rather than best first
+    #[arg(long)]
+    stream: bool,
+
+    /// Look through every solution for the best --max-solutions, rather than ranking the first ones found
+    #[arg(long, conflicts_with = "stream")]
+    best: bool,
+
+    /// Carry on from where an earlier --stream search stopped, with the cursor it gave. Implies --stream
+    #[arg(long, value_name = "CURSOR", conflicts_with = "best")]
+    after: Option<SearchCursor>,
+
+    /// How to print solutions
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Search on all CPU cores
+    #[cfg(feature = "parallel")]
+    #[arg(long)]
+    parallel: bool,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    #[command(flatten)]
+    board: BoardArgs,
+
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    /// The words of the answer in order, separated by dashes or commas (e.g., "forklift-twangy")
+    #[arg(long)]
+    answer: String,
+}
+
+#[derive(Args)]
+struct HintArgs {
+    #[command(flatten)]
+    board: BoardArgs,
+
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    #[command(flatten)]
+    word_range: WordRangeArgs,
+
+    /// How much to give away, from 1 (how many words it takes) through the first letter, length and
+    /// pattern of a good first word, to 5 (the word itself)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=5))]
+    level: u8,
+}
+
+#[derive(Args)]
+struct PlayArgs {
+    #[command(flatten)]
+    board: BoardArgs,
+
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    #[command(flatten)]
+    word_range: WordRangeArgs,
+}
+
+#[derive(Args)]
+struct DifficultyArgs {
+    #[command(flatten)]
+    board: BoardArgs,
+
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    #[command(flatten)]
+    word_range: WordRangeArgs,
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    #[command(flatten)]
+    board: BoardArgs,
+
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    #[command(flatten)]
+    word_range: WordRangeArgs,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    #[arg(long, default_value_t = Generator::DEFAULT_SIDES as u8, value_parser = clap::value_parser!(u8).range(3..))]
+    sides: u8,
+
+    #[arg(long, default_value_t = Generator::DEFAULT_LETTERS_PER_SIDE as u8, value_parser = clap::value_parser!(u8).range(1..))]
+    letters_per_side: u8,
+
+    /// Fewest vowels on the board (y doesn't count)
+    #[arg(long, default_value_t = *Generator::DEFAULT_VOWELS.start() as u8)]
+    min_vowels: u8,
+
+    /// Most vowels on the board
+    #[arg(long, default_value_t = *Generator::DEFAULT_VOWELS.end() as u8)]
+    max_vowels: u8,
+
+    /// Most vowels on any one side
+    #[arg(long, default_value_t = Generator::DEFAULT_MAX_VOWELS_PER_SIDE as u8)]
+    max_vowels_per_side: u8,
+
+    /// Most of the rare letters j, k, q, x and z on the board
+    #[arg(long, default_value_t = Generator::DEFAULT_MAX_RARE_LETTERS as u8)]
+    max_rare_letters: u8,
+
+    /// How common the words of the 2-word answer must be, from 0 to 31
+    #[arg(long, default_value_t = Generator::DEFAULT_MIN_FREQUENCY, value_parser = clap::value_parser!(i8).range(0..=31))]
+    min_frequency: i8,
+
+    /// Seed for the random number generator, to get the same board again
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Print the intended answer on a second line
+    #[arg(long)]
+    show_answer: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RankBy {
+    /// Common words first, and fewer words
+    Frequency,
+    /// Highest average word frequency
+    AverageFrequency,
+    /// Fewest letters in total
+    FewestLetters,
+    /// Longest single word
+    LongestWord,
+    /// Rarest words first
+    Obscure,
+}
+
+impl RankBy {
+    fn scorer(self) -> Box<dyn SolutionScorer> {
+        match self {
+            RankBy::Frequency => Box::new(MinFrequency),
+            RankBy::AverageFrequency => Box::new(AverageFrequency),
+            RankBy::FewestLetters => Box::new(FewestLetters),
+            RankBy::LongestWord => Box::new(LongestWord),
+            RankBy::Obscure => Box::new(MostObscure),
+        }
+    }
 }
 
 fn validate_board_spec(board_spec: &str) -> Result<Vec<String>, String> {
@@ -38,114 +269,353 @@ fn validate_board_spec(board_spec: &str) -> Result<Vec<String>, String> {
     Ok(sides)
 }
 
-fn main() -> std::io::Result<()> {
-    env_logger::init();
-    let args = Args::parse();
-
-    let max_solutions = args.max_solutions;
-
-    let dictionary_path = Path::new(&args.dictionary);
-
-    // Handle game - either from positional argument or --game option
-    let board = match (&args.board_spec, &args.board) {
-        (Some(spec), None) => {
-            // Parse comma-separated game specification
-            match validate_board_spec(spec) {
-                Ok(sides) => {
-                    debug!("Loading game from specification: {}", spec);
-                    match Board::from_sides(sides) {
-                        Ok(game) => game,
-                        Err(e) => {
-                            eprintln!("Error creating board from specification: {}", e);
-                            std::process::exit(1);
+impl BoardArgs {
+    /// Load the board, either from the positional specification or the --board file. Exits on errors.
+    fn load(&self) -> Board {
+        let board = match (&self.board_spec, &self.board) {
+            (Some(spec), None) => {
+                // Parse comma-separated game specification
+                match validate_board_spec(spec) {
+                    Ok(sides) => {
+                        debug!("Loading game from specification: {}", spec);
+                        match Board::from_sides(sides) {
+                            Ok(game) => game,
+                            Err(e) => {
+                                eprintln!("Error creating board from specification: {}", e);
+                                exit(1);
+                            }
                         }
                     }
+                    Err(e) => {
+                        eprintln!("Error parsing board specification: {}", e);
+                        exit(1);
+                    }
                 }
-                Err(e) => {
-                    eprintln!("Error parsing board specification: {}", e);
-                    std::process::exit(1);
+            }
+            (None, Some(path)) => {
+                // Load game from file
+                let game_path = Path::new(path);
+                debug!("Loading game from: {:?}", game_path);
+                match Board::from_path(game_path) {
+                    Ok(game) => game,
+                    Err(e) => {
+                        eprintln!("Error loading board: {}", e);
+                        exit(1);
+                    }
                 }
             }
+            (Some(_), Some(_)) => {
+                eprintln!("Error: Cannot specify both board specification and --board option");
+                exit(1);
+            }
+            (None, None) => {
+                eprintln!("Error: Either board specification or --board option is required");
+                exit(1);
+            }
+        };
+
+        debug!("Successfully loaded game:");
+        for (i, side) in board.sides.iter().enumerate() {
+            debug!("Side {}: {} ({} letters)", i, side, side.len());
         }
-        (None, Some(path)) => {
-            // Load game from file
-            let game_path = Path::new(path);
-            debug!("Loading game from: {:?}", game_path);
-            match Board::from_path(game_path) {
-                Ok(game) => game,
-                Err(e) => {
-                    eprintln!("Error loading board: {}", e);
-                    std::process::exit(1);
+        debug!(
+            "Number of valid digraphs in this game: {}",
+            board.digraphs.len()
+        );
+        debug!("Valid digraphs in this game:");
+        debug!("{}", format_valid_digraphs(&board.digraphs));
+
+        board
+    }
+}
+
+impl WordRangeArgs {
+    /// Restrict a solver to solutions of up to `max_words` words.
+    fn limit(&self, solver: Solver) -> Solver {
+        solver.with_word_range(Solver::DEFAULT_MIN_WORDS, self.max_words.into())
+    }
+}
+
+impl DictionaryArgs {
+    /// Load the dictionary. Exits on errors.
+    fn load(&self) -> Dictionary {
+        let dictionary_path = Path::new(&self.dictionary);
+        debug!("Loading dictionary from: {:?}", dictionary_path);
+        match Dictionary::from_path(dictionary_path) {
+            Ok((dictionary, malformed)) => {
+                for e in malformed {
+                    eprintln!("Skipping dictionary line. {}", e);
                 }
+                debug!("Successfully loaded dictionary:");
+                debug!("Number of words: {}", dictionary.words.len());
+                dictionary
+            }
+            Err(e) => {
+                eprintln!("Error loading dictionary: {}", e);
+                exit(1);
             }
         }
-        (Some(_), Some(_)) => {
-            eprintln!("Error: Cannot specify both board specification and --board option");
-            std::process::exit(1);
+    }
+}
+
+fn format_valid_digraphs(digraphs: &DigraphSet) -> String {
+    digraphs.to_strings().join(" ")
+}
+
+fn main() {
+    env_logger::init();
+    let cli = Cli::parse();
+
+    match cli.command.unwrap_or(Command::Solve(cli.solve)) {
+        Command::Solve(args) => solve(&args),
+        Command::Check(args) => check(&args),
+        Command::Hint(args) => hint(&args),
+        Command::Play(args) => play(&args),
+        Command::Stats(args) => stats(&args),
+        Command::Difficulty(args) => difficulty(&args),
+        Command::Generate(args) => generate(&args),
+    }
+}
+
+fn solve(args: &SolveArgs) {
+    if args.min_words > args.max_words {
+        eprintln!("Error: --min-words cannot be greater than --max-words");
+        exit(1);
+    }
+
+    let board = args.board.load();
+    let load_start = Instant::now();
+    let dictionary = args.dictionary.load();
+    let load_time = load_start.elapsed();
+
+    solve_board(board, &dictionary, load_time, args);
+}
+
+fn solve_board(board: Board, dictionary: &Dictionary, load_time: Duration, args: &SolveArgs) {
+    let max_solutions = args.max_solutions;
+
+    let solve_start = Instant::now();
+    let index = PlayableIndex::new(board, dictionary);
+    debug!("\nFirst 10 possible words for this game:");
+    for w in index.words().take(10) {
+        debug!("  {}", w.word);
+    }
+    debug!("Total possible words: {}", index.len());
+
+    // Run the solver
+    debug!("\nSolving the puzzle...");
+    let solver = Solver::from_index(&index, max_solutions)
+        .with_word_range(args.min_words.into(), args.max_words.into())
+        .with_scorer(args.rank_by.scorer());
+
+    let mut printer = Printer::new(args.format);
+    let mut next_cursor = None;
+    if args.stream || args.after.is_some() {
+        let mut search = match &args.after {
+            Some(cursor) => solver.solutions_from(cursor).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                exit(1);
+            }),
+            None => solver.solutions(),
+        };
+        for solution in search.by_ref().take(max_solutions.into()) {
+            printer.solution(&solution);
         }
-        (None, None) => {
-            eprintln!("Error: Either board specification or --board option is required");
-            std::process::exit(1);
+        next_cursor = search.cursor().map(|cursor| cursor.to_string());
+    } else {
+        let solutions = find_solutions(&solver, args);
+        for solution in solutions.iter() {
+            printer.solution(solution);
+            debug!("  {} {}", solution.score, solution.words.iter().map(|w| w.frequency.to_string()).collect::<Vec<_>>().join("-"));
         }
+    }
+
+    if printer.count() == 0 {
+        debug!("No solutions found!");
+    } else {
+        debug!("Found {} solutions.", printer.count());
+    }
+    let summary = Summary {
+        solutions: printer.count(),
+        dictionary_words: dictionary.words.len(),
+        playable_words: index.len(),
+        load_ms: load_time.as_secs_f64() * 1000.0,
+        solve_ms: solve_start.elapsed().as_secs_f64() * 1000.0,
+        next_cursor,
     };
+    printer.finish(&summary);
+}
+
+fn find_solutions(solver: &Solver, args: &SolveArgs) -> Vec<Solution> {
+    if args.best {
+        return solver.solve_best(None);
+    }
+    #[cfg(feature = "parallel")]
+    if args.parallel {
+        return solver.solve_parallel(None);
+    }
+    solver.solve()
+}
+
+fn check(args: &CheckArgs) {
+    let board = args.board.load();
+    let dictionary = args.dictionary.load();
+    let words = answer_words(&args.answer);
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
 
+    let check = board.check_answer(&words, &dictionary);
+    let answer = words.join("-");
 
-    pub fn format_valid_digraphs(digraphs: &HashSet<String>) -> String {
-        let mut sorted_digraphs: Vec<_> = digraphs.iter().collect();
-        sorted_digraphs.sort();
-        sorted_digraphs
-            .iter()
-            .map(|s| s.as_str())
-            .collect::<Vec<_>>()
-            .join(" ")
+    if !check.is_valid() {
+        println!("{} does not solve the board:", answer);
+        for problem in &check.problems {
+            println!("  {}", problem);
+        }
+
+        // If the words are fine as far as they go, say how far there is still to go
+        if check.problems.iter().all(|problem| matches!(problem, AnswerProblem::Uncovered { .. })) {
+            let solver = Solver::new(board, &dictionary, 1);
+            match solver.fewest_words_to_finish(&words) {
+                Ok(Some(1)) => println!("It can be finished in 1 more word"),
+                Ok(Some(more)) => println!("It can be finished in {} more words", more),
+                Ok(None) => println!("It can't be finished in {} more words or fewer", Solver::DEFAULT_MAX_WORDS),
+                Err(e) => println!("It can't be finished: {}", e),
+            }
+        }
+        exit(1);
     }
 
-    debug!("Successfully loaded game:");
-    for (i, side) in board.sides.iter().enumerate() {
-        debug!("Side {}: {} ({} letters)", i, side, side.len());
+    println!("{} solves the board in {} words", answer, words.len());
+    if let Some(covering) = &check.covering_words {
+        println!("but it's redundant: {} already use every letter", covering.join(", "));
     }
-    debug!(
-        "Number of valid digraphs in this game: {}",
-        board.digraphs.len()
-    );
-    debug!("Valid digraphs in this game:");
-    debug!("{}", format_valid_digraphs(&board.digraphs));
+}
+
+/// The words of an answer given on the command line, in lowercase like the board.
+fn answer_words(answer: &str) -> Vec<String> {
+    answer
+        .split(|c: char| c == '-' || c == ',' || c.is_whitespace())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
 
-    debug!("Loading dictionary from: {:?}", dictionary_path);
-    match Dictionary::from_path(dictionary_path) {
-        Ok(dictionary) => {
-            solve(board, dictionary, max_solutions);
+fn hint(args: &HintArgs) {
+    let board = args.board.load();
+    let dictionary = args.dictionary.load();
+    let solver = args.word_range.limit(Solver::new(board, &dictionary, 1));
+    let level = HintLevel::from_number(args.level).expect("clap checks the level");
+
+    match Hints::new(&solver) {
+        Some(hints) => {
+            for hint in hints.up_to(level) {
+                println!("{}", hint);
+            }
         }
-        Err(e) => eprintln!("Error loading dictionary: {}", e),
+        None => println!("This board has no solutions of {} words or fewer", args.word_range.max_words),
     }
+}
 
-    Ok(())
+fn play(args: &PlayArgs) {
+    let board = args.board.load();
+    let dictionary = args.dictionary.load();
+    let game = Game::new(board, &dictionary).with_max_words(args.word_range.max_words.into());
+    play::run(game, io::stdin().lock());
 }
 
-fn solve(board: Board, dictionary: Dictionary, max_solutions: u16) {
-    debug!("Successfully loaded dictionary:");
-    debug!("Number of words: {}", dictionary.words.len());
-    {
-        let board_dictionary = board.playable_dictionary(&dictionary);
-        debug!("\nFirst 10 possible words for this game:");
-        for w in board_dictionary.words.iter().take(10) {
-            debug!("  {}", w.word);
-        }
-        debug!("Total possible words: {}", board_dictionary.words.len());
-
-        // Run the solver
-        debug!("\nSolving the puzzle...");
-        let solver = Solver::new(board, &dictionary, max_solutions);
-        let solutions = solver.solve();
-
-        if solutions.is_empty() {
-            debug!("No solutions found!");
-        } else {
-            debug!("Found {} solutions.", solutions.len());
-            for solution in solutions.iter() {
-                println!("{}", solution);
-                debug!("  {} {}", solution.score, solution.words.iter().map(|w| w.frequency.to_string()).collect::<Vec<_>>().join("-"));
+fn stats(args: &StatsArgs) {
+    let board = args.board.load();
+    let dictionary = args.dictionary.load();
+    let index = PlayableIndex::new(board.clone(), &dictionary);
+    let letters: usize = board.sides.iter().map(|s| s.len()).sum();
+
+    println!("Board: {} ({} sides, {} letters)", board.sides.join(","), board.sides.len(), letters);
+    println!("Digraphs: {}", board.digraphs.len());
+    println!("Playable words: {} of {}", index.len(), dictionary.words.len());
+    let common: Vec<&str> = index.words().take(10).map(|w| w.word.as_str()).collect();
+    println!("Most common playable words: {}", common.join(", "));
+
+    let solver = args.word_range.limit(Solver::from_index(&index, 1));
+    let counts = solver.count_solutions();
+    println!("Solutions:");
+    for (words, count) in counts.iter() {
+        println!("  {} words: {}", words, count);
+    }
+    match counts.fewest_words() {
+        Some(words) => println!("Fewest words: {}", words),
+        None => println!("No solutions of {} words or fewer", args.word_range.max_words),
+    }
+}
+
+fn difficulty(args: &DifficultyArgs) {
+    let board = args.board.load();
+    let dictionary = args.dictionary.load();
+    let index = PlayableIndex::new(board, &dictionary);
+    let rating = DifficultyRating::new(&index, args.word_range.max_words.into());
+    let inputs = &rating.inputs;
+
+    println!("Difficulty: {} ({:.0} out of 100)", rating.difficulty, rating.rating);
+    match inputs.fewest_words {
+        Some(words) => println!("Fewest words: {}", words),
+        None => println!("Fewest words: no solutions of {} words or fewer", args.word_range.max_words),
+    }
+    for (words, count) in &inputs.solutions_by_length {
+        println!("  {} words: {} solutions", words, count);
+    }
+    if let Some(frequency) = inputs.best_frequency {
+        println!("Rarest word of the best solution: frequency {}", frequency);
+    }
+    println!("Playable words: {}", inputs.playable_words);
+
+    println!("Breakdown:");
+    for factor in &rating.factors {
+        println!("  {:<20} {:.2} x {:.0}%", factor.name, factor.value, factor.weight * 100.0);
+    }
+}
+
+fn generate(args: &GenerateArgs) {
+    let dictionary = args.dictionary.load();
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64)
+    });
+    debug!("Generating with seed {}", seed);
+
+    let mut generator = Generator::new(&dictionary, seed)
+        .with_shape(args.sides.into(), args.letters_per_side.into())
+        .with_vowels(args.min_vowels.into()..=args.max_vowels.into(), args.max_vowels_per_side.into())
+        .with_max_rare_letters(args.max_rare_letters.into())
+        .with_min_frequency(args.min_frequency);
+
+    match generator.generate() {
+        Ok(Some(puzzle)) => {
+            println!("{}", puzzle.board.sides.join(","));
+            if args.show_answer {
+                println!("{}", puzzle.answer);
             }
         }
+        Ok(None) => {
+            eprintln!("Error: couldn't find a board with a 2-word answer of common enough words. Try looser limits");
+            exit(1);
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_case_answer() {
+        let words = answer_words("FORKLIFT-Twangy");
+        assert_eq!(words, ["forklift", "twangy"]);
+
+        let board = Board::from_sides(["yfa", "otk", "lgw", "rni"].iter().map(|s| s.to_string()).collect()).unwrap();
+        let dictionary = Dictionary::from_strings(words.clone());
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        assert!(board.check_answer(&words, &dictionary).is_valid());
     }
 }
@@ -0,0 +1,148 @@
+use clap::ValueEnum;
+use letter_bounced::solver::Solution;
+use serde::Serialize;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One solution per line, e.g. "forklift-twangy"
+    Text,
+    /// A single JSON object holding every solution and a summary
+    Json,
+    /// One JSON object per line for each solution, then a final line with the summary
+    Ndjson,
+    /// A header, then one row per solution. The summary goes to stderr as JSON
+    Csv,
+}
+
+#[derive(Serialize)]
+pub struct SolutionRecord {
+    pub words: Vec<String>,
+    pub score: usize,
+    pub frequencies: Vec<i8>,
+    pub word_count: usize,
+    pub total_letters: usize,
+}
+
+impl From<&Solution> for SolutionRecord {
+    fn from(solution: &Solution) -> Self {
+        SolutionRecord {
+            words: solution.words.iter().map(|w| w.word.clone()).collect(),
+            score: solution.score,
+            frequencies: solution.words.iter().map(|w| w.frequency).collect(),
+            word_count: solution.words.len(),
+            total_letters: solution.words.iter().map(|w| w.word.len()).sum(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Summary {
+    pub solutions: usize,
+    pub dictionary_words: usize,
+    pub playable_words: usize,
+    pub load_ms: f64,
+    pub solve_ms: f64,
+    /// Where a streamed search stopped, to carry on with --after, unless it found every solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    solutions: &'a [SolutionRecord],
+    summary: &'a Summary,
+}
+
+#[derive(Serialize)]
+struct SummaryLine<'a> {
+    summary: &'a Summary,
+}
+
+/// Prints solutions as they arrive, in the chosen format. JSON has to wait for the summary to print anything.
+pub struct Printer {
+    format: Format,
+    pending: Vec<SolutionRecord>,
+    printed: usize,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        if format == Format::Csv {
+            println!("words,score,frequencies,word_count,total_letters");
+        }
+        Printer {
+            format,
+            pending: Vec::new(),
+            printed: 0,
+        }
+    }
+
+    pub fn solution(&mut self, solution: &Solution) {
+        self.printed += 1;
+        match self.format {
+            Format::Text => println!("{}", solution),
+            Format::Json => self.pending.push(solution.into()),
+            Format::Ndjson => println!("{}", to_json(&SolutionRecord::from(solution))),
+            Format::Csv => {
+                let record = SolutionRecord::from(solution);
+                let frequencies: Vec<String> = record.frequencies.iter().map(|f| f.to_string()).collect();
+                println!(
+                    "{},{},{},{},{}",
+                    record.words.join("-"),
+                    record.score,
+                    frequencies.join(" "),
+                    record.word_count,
+                    record.total_letters
+                );
+            }
+        }
+    }
+
+    /// How many solutions have been printed, or are waiting to be.
+    pub fn count(&self) -> usize {
+        self.printed
+    }
+
+    pub fn finish(self, summary: &Summary) {
+        match self.format {
+            Format::Text => {
+                if let Some(cursor) = &summary.next_cursor {
+                    eprintln!("There may be more. To carry on, add: --after {}", cursor);
+                }
+            }
+            Format::Json => println!(
+                "{}",
+                to_json(&Report {
+                    solutions: &self.pending,
+                    summary,
+                })
+            ),
+            Format::Ndjson => println!("{}", to_json(&SummaryLine { summary })),
+            Format::Csv => eprintln!("{}", to_json(&SummaryLine { summary })),
+        }
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("solutions always serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use letter_bounced::dictionary::Word;
+
+    #[test]
+    fn test_solution_record() {
+        let solution = Solution::new(vec![
+            Word::new("foxglove".to_string(), 16),
+            Word::new("equity".to_string(), 20),
+        ]);
+        let json = to_json(&SolutionRecord::from(&solution));
+
+        assert_eq!(
+            json,
+            r#"{"words":["foxglove","equity"],"score":80,"frequencies":[16,20],"word_count":2,"total_letters":14}"#
+        );
+    }
+}
@@ -0,0 +1,200 @@
+/*
+ * The packed binary dictionary format, which is about half the size of the text format and quicker to load,
+ * since there's no text to parse and the digraphs for the whole dictionary come precomputed.
+ *
+ * Version 1:
+ *
+ *     magic       6 bytes     "LBDICT"
+ *     version     1 byte      1
+ *     word count  4 bytes     little-endian u32
+ *     digraphs    85 bytes    676 bits, bit (a * 26 + b) is set if any word contains the letter pair "ab"
+ *     words       the rest    a bitstream, most significant bit first, padded with zeroes to a whole byte
+ *
+ * Each word in the stream is its frequency (5 bits, 0-31), its length (5 bits, 1-31), and then its letters
+ * (5 bits each, a = 0 to z = 25). Words keep their order from the text dictionary, so a dictionary sorted by
+ * frequency stays sorted.
+ */
+
+use crate::dictionary::{Dictionary, Word};
+use crate::digraph::{letter_index, DigraphSet, ALPHABET, DIGRAPH_COUNT};
+use crate::error::Error;
+
+const MAGIC: &[u8; 6] = b"LBDICT";
+const VERSION: u8 = 1;
+const DIGRAPH_BYTES: usize = (DIGRAPH_COUNT as usize).div_ceil(8);
+const HEADER_BYTES: usize = MAGIC.len() + 1 + 4 + DIGRAPH_BYTES;
+
+/// Everything is 5 bits: frequencies, lengths, and letters.
+const FIELD_BITS: u32 = 5;
+const MAX_FIELD: u8 = (1 << FIELD_BITS) - 1;
+
+pub(crate) fn is_packed(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+fn letter_code(ch: char) -> Option<u8> {
+    ch.is_ascii_lowercase().then(|| letter_index(ch) as u8)
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    position: usize, // in bits, from the start of the bitstream
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u8, bits: u32) {
+        for bit in (0..bits).rev() {
+            if self.position.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> bit) & 1 == 1 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.position % 8);
+            }
+            self.position += 1;
+        }
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize, // in bits
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, bits: u32) -> Result<u8, Error> {
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.bytes.get(self.position / 8).ok_or(Error::CorruptDictionary {
+                reason: "the word list is truncated",
+            })?;
+            let bit = (byte >> (7 - self.position % 8)) & 1;
+            value = (value << 1) | bit;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+}
+
+pub(crate) fn encode(dictionary: &Dictionary) -> Result<Vec<u8>, Error> {
+    let word_count = u32::try_from(dictionary.words.len()).map_err(|_| Error::TooManyWords {
+        count: dictionary.words.len(),
+    })?;
+
+    let mut bytes = Vec::with_capacity(HEADER_BYTES + dictionary.words.len() * 8);
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(VERSION);
+    bytes.extend_from_slice(&word_count.to_le_bytes());
+
+    let mut digraphs = [0u8; DIGRAPH_BYTES];
+    for index in dictionary.digraphs.indices().map(usize::from) {
+        digraphs[index / 8] |= 1 << (index % 8);
+    }
+    bytes.extend_from_slice(&digraphs);
+
+    let mut writer = BitWriter { bytes, position: 0 };
+    for word in &dictionary.words {
+        let unencodable = || Error::UnencodableWord {
+            word: word.word.clone(),
+        };
+        let letters: Vec<u8> = word.word.chars().map(letter_code).collect::<Option<_>>().ok_or_else(unencodable)?;
+        let frequency = u8::try_from(word.frequency).ok().filter(|&f| f <= MAX_FIELD).ok_or_else(unencodable)?;
+        let length = u8::try_from(letters.len()).ok().filter(|&l| (1..=MAX_FIELD).contains(&l)).ok_or_else(unencodable)?;
+
+        writer.write(frequency, FIELD_BITS);
+        writer.write(length, FIELD_BITS);
+        for letter in letters {
+            writer.write(letter, FIELD_BITS);
+        }
+    }
+
+    Ok(writer.bytes)
+}
+
+pub(crate) fn decode(data: &[u8]) -> Result<Dictionary, Error> {
+    if !is_packed(data) {
+        return Err(Error::CorruptDictionary { reason: "missing header" });
+    }
+    if data.len() < HEADER_BYTES {
+        return Err(Error::CorruptDictionary { reason: "the header is truncated" });
+    }
+
+    let version = data[MAGIC.len()];
+    if version != VERSION {
+        return Err(Error::UnsupportedDictionaryVersion { version });
+    }
+
+    let count_start = MAGIC.len() + 1;
+    let word_count = u32::from_le_bytes(data[count_start..count_start + 4].try_into().unwrap());
+    let digraph_bits = &data[count_start + 4..HEADER_BYTES];
+
+    let mut digraphs = DigraphSet::new();
+    for index in (0..DIGRAPH_COUNT).filter(|&index| digraph_bits[index as usize / 8] & (1 << (index % 8)) != 0) {
+        digraphs.insert_index(index);
+    }
+
+    let mut reader = BitReader {
+        bytes: &data[HEADER_BYTES..],
+        position: 0,
+    };
+    let words = (0..word_count)
+        .map(|_| {
+            let frequency = reader.read(FIELD_BITS)?;
+            let length = reader.read(FIELD_BITS)?;
+            let word = (0..length)
+                .map(|_| match reader.read(FIELD_BITS)? {
+                    code if (code as usize) < ALPHABET => Ok((b'a' + code) as char),
+                    _ => Err(Error::CorruptDictionary { reason: "invalid letter" }),
+                })
+                .collect::<Result<String, Error>>()?;
+            Ok(Word::new(word, frequency as i8))
+        })
+        .collect::<Result<Vec<Word>, Error>>()?;
+
+    Ok(Dictionary { words, digraphs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let (dictionary, _) = Dictionary::from_text("the 31\nfoxglove 16\nequity 20\nzyzzyva 0\n");
+        let bytes = encode(&dictionary).unwrap();
+
+        assert!(is_packed(&bytes));
+        let decoded = decode(&bytes).unwrap();
+        assert_eq!(decoded.words, dictionary.words);
+        assert_eq!(decoded.digraphs, dictionary.digraphs);
+
+        // 4 words of 3 + 8 + 6 + 7 letters, at 5 bits per letter plus 10 bits each for frequency and length
+        assert_eq!(bytes.len(), HEADER_BYTES + (24 * 5 + 4 * 10usize).div_ceil(8));
+    }
+
+    #[test]
+    fn test_unencodable_words() {
+        let uppercase = Dictionary::from_words(vec![Word::new("FOX".to_string(), 15)]);
+        assert!(matches!(encode(&uppercase), Err(Error::UnencodableWord { .. })));
+
+        let too_frequent = Dictionary::from_words(vec![Word::new("fox".to_string(), 32)]);
+        assert!(matches!(encode(&too_frequent), Err(Error::UnencodableWord { .. })));
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let (dictionary, _) = Dictionary::from_text("fox 15\n");
+        let mut bytes = encode(&dictionary).unwrap();
+        bytes[MAGIC.len()] = VERSION + 1;
+
+        assert!(matches!(decode(&bytes), Err(Error::UnsupportedDictionaryVersion { version: 2 })));
+    }
+
+    #[test]
+    fn test_truncated() {
+        let (dictionary, _) = Dictionary::from_text("foxglove 16\nequity 20\n");
+        let bytes = encode(&dictionary).unwrap();
+
+        assert!(matches!(decode(&bytes[..bytes.len() - 2]), Err(Error::CorruptDictionary { .. })));
+        assert!(matches!(decode(&bytes[..HEADER_BYTES - 1]), Err(Error::CorruptDictionary { .. })));
+    }
+}
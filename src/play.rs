@@ -0,0 +1,168 @@
+use letter_bounced::game::Game;
+use letter_bounced::hint::{Hint, HintLevel};
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "Type a word to play it. Letters already used are shown in capitals.
+Commands: :undo takes back the last word, :hint gives a hint (ask again for a bigger one), :quit gives up";
+
+/// Play a game on the terminal, reading words and commands from `input` until the board is solved.
+pub fn run(mut game: Game, input: impl BufRead) {
+    println!("{}\n", HELP);
+    print!("{}", render(&game));
+    prompt(&game);
+
+    // Each :hint gives away a little more, until the next word is played or taken back
+    let mut hint_level = 0;
+
+    for line in input.lines() {
+        let Ok(line) = line else { break };
+        let entry = line.trim().to_lowercase();
+
+        match entry.as_str() {
+            "" => {}
+            ":quit" | ":q" => return,
+            ":help" => println!("{}", HELP),
+            ":undo" => match game.undo() {
+                Some(word) => {
+                    println!("Took back {}", word);
+                    hint_level = 0;
+                    print!("{}", render(&game));
+                }
+                None => println!("There's nothing to take back"),
+            },
+            ":hint" => match game.hints() {
+                Some(hints) => {
+                    hint_level = (hint_level + 1).min(HintLevel::ALL.len());
+                    println!("{}", describe(&hints.hint(HintLevel::ALL[hint_level - 1])));
+                }
+                None => println!("There's no way to finish from here. Try :undo"),
+            },
+            command if command.starts_with(':') => println!("Unknown command {}. Try :help", command),
+            word => match game.play(word) {
+                Ok(()) => {
+                    hint_level = 0;
+                    print!("{}", render(&game));
+                    if game.is_won() {
+                        announce_win(&game);
+                        return;
+                    }
+                }
+                Err(problems) => {
+                    for problem in problems {
+                        println!("Can't play that: {}", problem);
+                    }
+                }
+            },
+        }
+        prompt(&game);
+    }
+    println!();
+}
+
+fn prompt(game: &Game) {
+    match game.next_letter() {
+        Some(letter) => print!("{}... > ", letter),
+        None => print!("> "),
+    }
+    let _ = io::stdout().flush();
+}
+
+/// Hints are about the next word, which is only the first word at the start of the game.
+fn describe(hint: &Hint) -> String {
+    match hint {
+        Hint::WordCount { words: 1 } => "You can finish with one more word".to_string(),
+        Hint::WordCount { words } => format!("You can finish in {} more words", words),
+        Hint::FirstLetter { letter } => format!("The next word starts with '{}'", letter),
+        Hint::Length { letters } => format!("The next word has {} letters", letters),
+        Hint::Pattern { pattern } => format!("The next word looks like {}", pattern),
+        Hint::FirstWord { word } => format!("The next word is {}", word),
+    }
+}
+
+fn announce_win(game: &Game) {
+    let played = game.words();
+    println!("\nSolved in {} words: {}", played.len(), played.join("-"));
+
+    match game.best_solution() {
+        Some(best) if best.words.iter().map(|w| &w.word).eq(played) => {
+            println!("That's the solver's favourite solution too!");
+        }
+        Some(best) if best.words.len() == played.len() => {
+            println!("That's as few words as the solver's best, {}", best);
+        }
+        Some(best) => println!("The solver's best takes {} words: {}", best.words.len(), best),
+        None => println!("That's better than the solver managed"),
+    }
+}
+
+/// The board as a box, with the letters already used in capitals. Boards which aren't square are listed a
+/// side at a time instead.
+fn render(game: &Game) -> String {
+    let letters = |side: &str| -> Vec<char> {
+        side.chars()
+            .map(|ch| if game.is_covered(ch) { ch.to_ascii_uppercase() } else { ch })
+            .collect()
+    };
+    let sides: Vec<Vec<char>> = game.board().sides.iter().map(|side| letters(side)).collect();
+
+    let mut out = String::new();
+    if let [top, right, bottom, left] = &sides[..] {
+        let width = top.len().max(bottom.len());
+        let height = left.len().max(right.len());
+        let border = format!("   +{}+\n", "-".repeat(4 * width - 1));
+        let across = |side: &[char]| {
+            let spaced: Vec<String> = side.iter().map(char::to_string).collect();
+            format!("{}{}\n", " ".repeat(5 + 2 * (width - side.len())), spaced.join("   "))
+        };
+        // Shorter sides sit in the middle
+        let down = |side: &[char], row: usize| {
+            let offset = (height - side.len()) / 2;
+            row.checked_sub(offset).and_then(|i| side.get(i)).copied().unwrap_or(' ')
+        };
+
+        out += &across(top);
+        out += &border;
+        for row in 0..height {
+            out += &format!(" {} |{}| {}\n", down(left, row), " ".repeat(4 * width - 1), down(right, row));
+        }
+        out += &border;
+        out += &across(bottom);
+    } else {
+        for (i, side) in sides.iter().enumerate() {
+            let spaced: Vec<String> = side.iter().map(char::to_string).collect();
+            out += &format!("side {}: {}\n", i + 1, spaced.join(" "));
+        }
+    }
+
+    if !game.words().is_empty() {
+        out += &format!("\nPlayed: {}\n", game.words().join("-"));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use letter_bounced::board::Board;
+    use letter_bounced::dictionary::Dictionary;
+
+    #[test]
+    fn test_render() {
+        let board = Board::from_sides(["yfa", "otk", "lgw", "rni"].iter().map(|s| s.to_string()).collect()).unwrap();
+        let dictionary = Dictionary::from_strings(vec!["forklift".to_string()]);
+        let mut game = Game::new(board, &dictionary);
+        game.play("forklift").unwrap();
+
+        let expected = "     y   F   a
+   +-----------+
+ R |           | O
+ n |           | T
+ I |           | K
+   +-----------+
+     L   g   w
+
+Played: forklift
+";
+        assert_eq!(render(&game), expected);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::board::Board;
+use crate::dictionary::{Dictionary, Word};
+use crate::digraph::{letter_index, ALPHABET};
+use crate::error::Error;
+
+/// The words of a dictionary which are playable on a board.
+///
+/// Building one filters the whole dictionary, but after that the board can be changed a letter at a time,
+/// re-checking only the words which contain the old or new letter. That's what an editor wants as someone
+/// types a board in.
+#[derive(Debug, Clone)]
+pub struct PlayableIndex<'a> {
+    dictionary: &'a Dictionary,
+    board: Board,
+    playable: Vec<bool>, // indexed like dictionary.words
+    words_by_letter: Option<Vec<Vec<u32>>>, // built the first time a letter changes
+}
+
+impl<'a> PlayableIndex<'a> {
+    pub fn new(board: Board, dictionary: &'a Dictionary) -> Self {
+        let playable = dictionary
+            .words
+            .iter()
+            .map(|word| word.digraphs.is_subset(&board.digraphs))
+            .collect();
+
+        PlayableIndex {
+            dictionary,
+            board,
+            playable,
+            words_by_letter: None,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// The playable words, in dictionary order.
+    pub fn words(&self) -> impl Iterator<Item = &'a Word> + '_ {
+        let words = &self.dictionary.words;
+        self.playable
+            .iter()
+            .enumerate()
+            .filter(|(_, &playable)| playable)
+            .map(move |(i, _)| &words[i])
+    }
+
+    pub fn len(&self) -> usize {
+        self.playable.iter().filter(|&&playable| playable).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.playable.contains(&true)
+    }
+
+    /// Swap the letter `old` on the board for `new`, which must not already be on the board.
+    pub fn replace_letter(&mut self, old: char, new: char) -> Result<(), Error> {
+        if !self.board.sides.iter().any(|side| side.contains(old)) {
+            return Err(Error::LetterNotOnBoard { ch: old });
+        }
+        if old == new {
+            return Ok(());
+        }
+
+        let sides = self
+            .board
+            .sides
+            .iter()
+            .map(|side| side.chars().map(|ch| if ch == old { new } else { ch }).collect())
+            .collect();
+        self.board = Board::from_sides(sides)?;
+
+        let dictionary = self.dictionary;
+        let words_by_letter = self.words_by_letter.get_or_insert_with(|| index_by_letter(dictionary));
+
+        // Words using the old letter can't be played any more. Words using neither letter are unaffected,
+        // since the board only gained and lost digraphs involving these two.
+        for &i in &words_by_letter[letter_index(old)] {
+            self.playable[i as usize] = false;
+        }
+        for &i in &words_by_letter[letter_index(new)] {
+            let word = &dictionary.words[i as usize];
+            self.playable[i as usize] = word.digraphs.is_subset(&self.board.digraphs);
+        }
+
+        Ok(())
+    }
+
+    /// Move to a different board. If it's the same shape with one letter changed, only the words touching
+    /// that letter are re-checked; otherwise the whole dictionary is filtered again.
+    pub fn set_board(&mut self, board: Board) {
+        let same_shape = board.sides.len() == self.board.sides.len()
+            && board.sides.iter().zip(&self.board.sides).all(|(a, b)| a.len() == b.len());
+        if same_shape {
+            let old_letters = self.board.sides.iter().flat_map(|side| side.chars());
+            let new_letters = board.sides.iter().flat_map(|side| side.chars());
+            let changes: Vec<(char, char)> = old_letters.zip(new_letters).filter(|(old, new)| old != new).collect();
+            match changes[..] {
+                [] => return,
+                // The new board is valid, so the new letter can't be elsewhere on the old one
+                [(old, new)] if self.replace_letter(old, new).is_ok() => return,
+                _ => {}
+            }
+        }
+
+        *self = PlayableIndex {
+            words_by_letter: self.words_by_letter.take(),
+            ..PlayableIndex::new(board, self.dictionary)
+        };
+    }
+}
+
+/// For each letter, the indices of the words which contain it.
+fn index_by_letter(dictionary: &Dictionary) -> Vec<Vec<u32>> {
+    let mut words_by_letter = vec![Vec::new(); ALPHABET];
+    for (i, word) in dictionary.words.iter().enumerate() {
+        let mut seen = 0u32;
+        for ch in word.word.chars().filter(char::is_ascii_lowercase) {
+            let letter = letter_index(ch);
+            if seen & (1 << letter) == 0 {
+                seen |= 1 << letter;
+                words_by_letter[letter].push(i as u32);
+            }
+        }
+    }
+    words_by_letter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{board, board_from_strs, dictionary};
+
+    fn words(index: &PlayableIndex) -> Vec<String> {
+        index.words().map(|w| w.word.clone()).collect()
+    }
+
+    #[test]
+    fn test_matches_playable_dictionary() {
+        let dictionary = dictionary();
+        let board = board();
+        let index = PlayableIndex::new(board.clone(), &dictionary);
+
+        let expected: Vec<String> = board.playable_dictionary(&dictionary).words.into_iter().map(|w| w.word).collect();
+        assert_eq!(words(&index), expected);
+        assert_eq!(index.len(), expected.len());
+    }
+
+    #[test]
+    fn test_replace_letter() {
+        let dictionary = dictionary();
+        let mut index = PlayableIndex::new(board(), &dictionary);
+        assert!(words(&index).contains(&"foxglove".to_string()));
+
+        // Swapping v for c loses foxglove and vex
+        index.replace_letter('v', 'c').unwrap();
+        let fresh = PlayableIndex::new(board_from_strs(&["cyq", "fig", "ote", "xlu"]), &dictionary);
+        assert_eq!(index.board().sides, fresh.board().sides);
+        assert_eq!(words(&index), words(&fresh));
+        assert!(!words(&index).contains(&"foxglove".to_string()));
+
+        // And swapping back restores them
+        index.replace_letter('c', 'v').unwrap();
+        let original = PlayableIndex::new(board(), &dictionary);
+        assert_eq!(words(&index), words(&original));
+    }
+
+    #[test]
+    fn test_replace_letter_errors() {
+        let dictionary = dictionary();
+        let mut index = PlayableIndex::new(board(), &dictionary);
+
+        assert!(matches!(index.replace_letter('z', 'a'), Err(Error::LetterNotOnBoard { ch: 'z' })));
+        assert!(matches!(index.replace_letter('v', 'f'), Err(Error::DuplicateLetter { ch: 'f', .. })));
+        assert_eq!(index.board().sides[0], "vyq");
+    }
+
+    #[test]
+    fn test_set_board() {
+        let dictionary = dictionary();
+        let mut index = PlayableIndex::new(board(), &dictionary);
+
+        for sides in [&["vyq", "fig", "ote", "xlc"][..], &["abc", "fig", "ote", "xlu"], &["vyq", "fig", "ote"]] {
+            index.set_board(board_from_strs(sides));
+            assert_eq!(words(&index), words(&PlayableIndex::new(board_from_strs(sides), &dictionary)));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::dictionary::Word;
+use std::cmp::{max, min};
+
+/// Frequency scores in the dictionary run from 0 (rarest) to 31 (most common).
+const MAX_FREQUENCY: usize = 31;
+
+/// Decides how good a solution is. Solutions with higher scores are shown first.
+pub trait SolutionScorer: Send + Sync {
+    fn score(&self, words: &[Word]) -> usize;
+
+    /// The best score that any solution of `target_words` words starting with `prefix` could get.
+    ///
+    /// `Solver::solve_best` uses this to skip branches of the search which can't beat the solutions
+    /// it already has. Return `None` if there is no cheap bound; the search will still be correct,
+    /// just slower.
+    fn upper_bound(&self, _prefix: &[&Word], _target_words: usize) -> Option<usize> {
+        None
+    }
+}
+
+fn frequency(word: &Word) -> usize {
+    min(max(word.frequency, 0) as usize, MAX_FREQUENCY)
+}
+
+/// The default ranking: a solution is only as good as its rarest word, and fewer words are better.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinFrequency;
+
+impl SolutionScorer for MinFrequency {
+    fn score(&self, words: &[Word]) -> usize {
+        let min_frequency: usize = words.iter().fold(256usize, |acc, w| min(acc, w.frequency as usize));
+        (min_frequency * 10) / words.len()
+    }
+
+    fn upper_bound(&self, prefix: &[&Word], target_words: usize) -> Option<usize> {
+        // Adding words can only lower the minimum
+        let min_frequency: usize = prefix.iter().fold(256usize, |acc, w| min(acc, w.frequency as usize));
+        Some((min_frequency * 10) / target_words)
+    }
+}
+
+/// Prefers solutions whose words are common on average, so one rare word can be made up for by the others.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AverageFrequency;
+
+impl SolutionScorer for AverageFrequency {
+    fn score(&self, words: &[Word]) -> usize {
+        let total: usize = words.iter().map(frequency).sum();
+        (total * 10) / words.len()
+    }
+
+    fn upper_bound(&self, prefix: &[&Word], target_words: usize) -> Option<usize> {
+        // At best, the rest of the words are as common as words get
+        let total: usize = prefix.iter().map(|w| frequency(w)).sum();
+        let remaining = target_words.saturating_sub(prefix.len());
+        Some(((total + remaining * MAX_FREQUENCY) * 10) / target_words)
+    }
+}
+
+/// Prefers solutions with the fewest letters in total.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FewestLetters;
+
+impl SolutionScorer for FewestLetters {
+    fn score(&self, words: &[Word]) -> usize {
+        // Higher scores are better, so invert the letter count
+        let letters: usize = words.iter().map(|w| w.word.len()).sum();
+        10_000 / max(letters, 1)
+    }
+
+    fn upper_bound(&self, prefix: &[&Word], target_words: usize) -> Option<usize> {
+        // Every word still to come has at least one letter
+        let letters: usize = prefix.iter().map(|w| w.word.len()).sum();
+        let remaining = target_words.saturating_sub(prefix.len());
+        Some(10_000 / max(letters + remaining, 1))
+    }
+}
+
+/// Prefers solutions containing a single long word.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LongestWord;
+
+impl SolutionScorer for LongestWord {
+    fn score(&self, words: &[Word]) -> usize {
+        words.iter().map(|w| w.word.len()).max().unwrap_or(0)
+    }
+}
+
+/// The opposite of `MinFrequency`: a solution is only as obscure as its most common word.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MostObscure;
+
+impl SolutionScorer for MostObscure {
+    fn score(&self, words: &[Word]) -> usize {
+        let max_frequency = words.iter().map(frequency).max().unwrap_or(MAX_FREQUENCY);
+        ((MAX_FREQUENCY - max_frequency) * 10) / words.len()
+    }
+
+    fn upper_bound(&self, prefix: &[&Word], target_words: usize) -> Option<usize> {
+        // Adding words can only raise the maximum
+        let max_frequency = prefix.iter().map(|w| frequency(w)).max().unwrap_or(0);
+        Some(((MAX_FREQUENCY - max_frequency) * 10) / target_words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(spec: &[(&str, i8)]) -> Vec<Word> {
+        spec.iter().map(|&(w, f)| Word::new(w.to_string(), f)).collect()
+    }
+
+    #[test]
+    fn test_builtin_scorers() {
+        let solution = words(&[("foxglove", 16), ("equity", 20)]);
+
+        assert_eq!(MinFrequency.score(&solution), 80);
+        assert_eq!(AverageFrequency.score(&solution), 180);
+        assert_eq!(FewestLetters.score(&solution), 714);
+        assert_eq!(LongestWord.score(&solution), 8);
+        assert_eq!(MostObscure.score(&solution), 55);
+    }
+
+    #[test]
+    fn test_fewest_letters_prefers_shorter() {
+        let short = words(&[("fog", 16), ("glove", 16)]);
+        let long = words(&[("foxglove", 16), ("equity", 20)]);
+
+        assert!(FewestLetters.score(&short) > FewestLetters.score(&long));
+    }
+}
@@ -1,8 +1,13 @@
 use crate::board::Board;
 use crate::dictionary::{Dictionary, Word};
-use std::collections::HashMap;
+use crate::error::Error;
+use crate::playable::PlayableIndex;
+use crate::scorer::{MinFrequency, SolutionScorer};
+use serde::{Deserialize, Serialize};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::fmt;
-use std::cmp::min;
+use std::str::FromStr;
+use std::cmp::{Ordering as CmpOrdering, Reverse};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -14,8 +19,11 @@ pub struct Solution {
 
 impl Solution {
     pub fn new(words: Vec<Word>) -> Self {
-        let min_frequency: usize = words.iter().fold(256usize, |acc, w| min(acc, w.frequency as usize));
-        let score: usize = (min_frequency * 10) / words.len();
+        Self::with_scorer(words, &MinFrequency)
+    }
+
+    pub fn with_scorer(words: Vec<Word>, scorer: &dyn SolutionScorer) -> Self {
+        let score = scorer.score(&words);
         Solution { words, score }
     }
 
@@ -27,60 +35,45 @@ impl Solution {
     /// All redactions must be shorter than the original solution.
     pub fn redactable_subsequences(&self) -> Vec<Vec<usize>> {
         let n = self.words.len();
-        if n <= 1 {
-            return vec![];
-        }
-
-        let mut redactions = Vec::new();
-
-        // Generate all non-empty proper subsequences (shorter than original)
-        // Use bitmask to represent which words to include
-        let num_subsets = 1 << n;
-        for mask in 1..num_subsets {
-            // Skip if this includes all words (not a proper subsequence)
-            if mask == num_subsets - 1 {
-                continue;
-            }
-
-            let mut indices = Vec::new();
-            for i in 0..n {
-                if (mask & (1 << i)) != 0 {
-                    indices.push(i);
-                }
-            }
+        let chains = |i: usize, j: usize| {
+            let last_char = self.words[i].word.chars().last();
+            let first_char = self.words[j].word.chars().next();
+            last_char == first_char
+        };
 
-            // Check if this subsequence is redactable
-            if indices.is_empty() {
-                continue;
-            }
+        redactable_masks(n, chains)
+            .map(|mask| (0..n).filter(|i| (mask & (1 << i)) != 0).collect())
+            .collect()
+    }
 
-            // Rule 1: Includes the head (index 0 not set in mask means head is removed)
-            let includes_head = (mask & 1) == 0;
-
-            // Rule 2: Check if it forms a valid chain (if head is included)
-            let forms_valid_chain = if !includes_head {
-                // Check that consecutive words in the subsequence form valid chains
-                let mut valid = true;
-                for i in 0..indices.len() - 1 {
-                    let last_char = self.words[indices[i]].word.chars().last();
-                    let first_char = self.words[indices[i + 1]].word.chars().next();
-                    if last_char != first_char {
-                        valid = false;
-                        break;
-                    }
-                }
-                valid
-            } else {
-                true
-            };
+    /// For each word, the letters it uses that no earlier word did, in the order it uses them.
+    pub fn newly_covered(&self) -> Vec<String> {
+        let mut covered = HashSet::new();
+        self.words
+            .iter()
+            .map(|w| w.word.chars().filter(|&ch| covered.insert(ch)).collect())
+            .collect()
+    }
+}
 
-            if includes_head || forms_valid_chain {
-                redactions.push(indices);
-            }
+/// The redactable subsequences of a chain of `n` words, as bitmasks of the words they keep.
+/// See `Solution::redactable_subsequences` for the rules. `chains(i, j)` says whether word `j`
+/// could directly follow word `i`.
+fn redactable_masks(n: usize, chains: impl Fn(usize, usize) -> bool) -> impl Iterator<Item = usize> {
+    // Generate all non-empty proper subsequences (shorter than original)
+    // Use bitmask to represent which words to include
+    let num_subsets: usize = 1 << n;
+    (1..num_subsets.saturating_sub(1)).filter(move |&mask| {
+        // Rule 1: Includes the head (index 0 not set in mask means head is removed)
+        let includes_head = (mask & 1) == 0;
+        if includes_head {
+            return true;
         }
 
-        redactions
-    }
+        // Rule 2: Check that consecutive words in the subsequence form valid chains
+        let kept = (0..n).filter(|i| (mask & (1 << i)) != 0);
+        kept.clone().zip(kept.skip(1)).all(|(i, j)| chains(i, j))
+    })
 }
 
 impl fmt::Display for Solution {
@@ -94,21 +87,51 @@ impl fmt::Display for Solution {
     }
 }
 
+/// A board can have at most 26 letters, since `Board` only accepts distinct letters from a-z,
+/// so a `u32` always has a bit to spare for every letter, however many sides the board has.
+const MAX_BOARD_LETTERS: u32 = 26;
+
 struct WordBitmap {
     word: Word,
     bitmap: u32,
+    first_char: char,
+    last_char: char,
+}
+
+/// What matters about a word when deciding whether a search can still succeed: the letters it covers,
+/// and the letter the next word has to start with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct WordShape {
+    bitmap: u32,
+    last_char: char,
 }
 
 pub struct Solver {
     word_bitmaps: Vec<WordBitmap>,
     words_by_first_letter: HashMap<char, Vec<usize>>,
+    shapes_by_first_letter: HashMap<char, Vec<WordShape>>,
+    all_words: Vec<usize>,
     all_letters_mask: u32,
     max_solutions: usize, // this is usize for convenience in comparisons to length(), but set from u16
+    min_words: usize,
+    max_words: usize,
+    scorer: Box<dyn SolutionScorer>,
 }
 
 impl Solver {
+    pub const DEFAULT_MIN_WORDS: usize = 1;
+    pub const DEFAULT_MAX_WORDS: usize = 4;
+
     pub fn new(board: Board, dictionary: &Dictionary, max_solutions: u16) -> Self {
-        // Create letter-to-bit mapping
+        Self::from_index(&PlayableIndex::new(board, dictionary), max_solutions)
+    }
+
+    /// Build a solver from a board's playable words, which can be kept and updated between solves.
+    pub fn from_index(index: &PlayableIndex, max_solutions: u16) -> Self {
+        let board = index.board();
+
+        // Create letter-to-bit mapping. Sides can differ in number and length, so we just number
+        // the letters in order around the board.
         let mut letter_to_bit = HashMap::new();
         let mut bit_index = 0;
         for side in &board.sides {
@@ -119,13 +142,12 @@ impl Solver {
         }
 
         // Calculate mask for all letters, e.g. for 8 letters, this is 0b11111111
-        let all_letters_mask = 2u32.pow(bit_index) - 1;
+        assert!(bit_index <= MAX_BOARD_LETTERS, "Board has {} letters, more than the alphabet", bit_index);
+        let all_letters_mask = (1u32 << bit_index) - 1;
 
         // Create word bitmaps for all words playable
-        let board_dictionary = board.playable_dictionary(&dictionary);
-        let word_bitmaps: Vec<WordBitmap> = board_dictionary
-            .words
-            .iter()
+        let word_bitmaps: Vec<WordBitmap> = index
+            .words()
             .map(|word| {
                 let bitmap = word.word.chars().fold(0, |acc, ch| {
                     acc | letter_to_bit.get(&ch).copied().unwrap_or(0)
@@ -133,6 +155,8 @@ impl Solver {
                 WordBitmap {
                     word: word.clone(),
                     bitmap,
+                    first_char: word.word.chars().next().unwrap_or_default(),
+                    last_char: word.word.chars().last().unwrap_or_default(),
                 }
             })
             .collect();
@@ -145,37 +169,98 @@ impl Solver {
             }
         }
 
+        // Many words share a shape, so the dead end checks can skip the duplicates
+        let shapes_by_first_letter = words_by_first_letter
+            .iter()
+            .map(|(&first_char, word_indices)| {
+                let mut seen = HashSet::new();
+                let shapes = word_indices
+                    .iter()
+                    .map(|&i| WordShape {
+                        bitmap: word_bitmaps[i].bitmap,
+                        last_char: word_bitmaps[i].last_char,
+                    })
+                    .filter(|shape| seen.insert(*shape))
+                    .collect();
+                (first_char, shapes)
+            })
+            .collect();
+
         Solver {
+            all_words: (0..word_bitmaps.len()).collect(),
             word_bitmaps,
             words_by_first_letter,
+            shapes_by_first_letter,
             all_letters_mask,
             max_solutions: max_solutions.into(),
+            min_words: Self::DEFAULT_MIN_WORDS,
+            max_words: Self::DEFAULT_MAX_WORDS,
+            scorer: Box::new(MinFrequency),
         }
     }
 
+    /// Rank solutions with `scorer` instead of the default `MinFrequency`.
+    pub fn with_scorer(mut self, scorer: Box<dyn SolutionScorer>) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Only look for solutions with between `min_words` and `max_words` words, inclusive.
+    ///
+    /// By default we look for solutions of 1 to 4 words, which is enough for typical boards, but
+    /// harder boards or restricted dictionaries may only have longer solutions.
+    pub fn with_word_range(mut self, min_words: usize, max_words: usize) -> Self {
+        assert!(
+            min_words >= 1 && min_words <= max_words,
+            "Invalid word range {}..={}",
+            min_words,
+            max_words
+        );
+        self.min_words = min_words;
+        self.max_words = max_words;
+        self
+    }
+
     /// Check if a solution is redundant by examining its redactable subsequences.
     /// A solution is redundant if any of its redactions also covers all letters.
-    fn is_solution_redundant(&self, solution: &Solution) -> bool {
-        let redaction_indices = solution.redactable_subsequences();
+    ///
+    /// `path` holds the indices into `word_bitmaps` of the words in the solution.
+    fn is_path_redundant(&self, path: &[usize]) -> bool {
+        self.is_redundant_after(path, 0)
+    }
 
-        for indices in redaction_indices {
-            // Compute the combined bitmap for this redaction by indexing into solution
-            let mut combined_bitmap = 0u32;
-            for &idx in &indices {
-                let word = &solution.words[idx];
-                // Find the bitmap for this word
-                if let Some(wb) = self.word_bitmaps.iter().find(|wb| wb.word == *word) {
-                    combined_bitmap |= wb.bitmap;
-                }
-            }
+    /// Like `is_path_redundant`, but the first `played` words of the path have already been played, so
+    /// only redactions which keep all of them count.
+    fn is_redundant_after(&self, path: &[usize], played: usize) -> bool {
+        let chains = |i: usize, j: usize| {
+            self.word_bitmaps[path[i]].last_char == self.word_bitmaps[path[j]].first_char
+        };
+        let played_mask = (1 << played) - 1;
+
+        redactable_masks(path.len(), chains).filter(|mask| mask & played_mask == played_mask).any(|mask| {
+            // Compute the combined bitmap for this redaction by indexing into the path
+            let combined_bitmap = path
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (mask & (1 << i)) != 0)
+                .fold(0u32, |acc, (_, &idx)| acc | self.word_bitmaps[idx].bitmap);
 
             // If this redaction covers all letters, the original solution is redundant
-            if combined_bitmap == self.all_letters_mask {
-                return true;
-            }
-        }
+            combined_bitmap == self.all_letters_mask
+        })
+    }
 
-        false
+    fn solution_for(&self, path: &[usize]) -> Solution {
+        let words = path.iter().map(|&i| self.word_bitmaps[i].word.clone()).collect();
+        Solution::with_scorer(words, self.scorer.as_ref())
+    }
+
+    /// Word indices which may follow a word ending in `last_char`.
+    fn next_words(&self, last_char: char) -> &[usize] {
+        self.words_by_first_letter
+            .get(&last_char)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
     }
 
     pub fn solve(&self) -> Vec<Solution> {
@@ -187,108 +272,868 @@ impl Solver {
     /// The `cancel_flag` parameter allows external cancellation of the solve operation.
     /// When the flag is set to true, the solver will stop as soon as possible.
     pub fn solve_cancellable(&self, cancel_flag: Option<Arc<AtomicBool>>) -> Vec<Solution> {
-        let mut solutions = Vec::new();
+        let mut solutions: Vec<Solution> = self
+            .solutions_cancellable(cancel_flag)
+            .take(self.max_solutions)
+            .collect();
+
+        // Sort by score descending
+        solutions.sort_by_key(|s| Reverse(s.score));
+
+        solutions
+    }
 
-        // Try solutions of each exact length
-        for target_words in 1..=4 {
-            let mut current_path = Vec::new();
-            let cancelled = !self.search_recursive(
-                &mut current_path,
-                0,
-                None,
-                &mut solutions,
-                target_words,
-                cancel_flag.as_ref(),
-            );
-
-            if cancelled || solutions.len() >= self.max_solutions {
-                break;
+    /// Find the `max_solutions` highest-scoring solutions.
+    ///
+    /// `solve` stops as soon as it has found `max_solutions` solutions, and only then sorts them, so
+    /// it returns the best of the first solutions found. This considers every solution instead,
+    /// skipping branches of the search which the scorer's upper bound says can't make the cut.
+    /// Ties are broken in the order `solve` would have found them.
+    pub fn solve_best(&self, cancel_flag: Option<Arc<AtomicBool>>) -> Vec<Solution> {
+        let mut top = TopSolutions::new(self, Vec::new(), self.max_solutions, cancel_flag.as_ref());
+        if self.max_solutions > 0 {
+            for target_words in self.min_words..=self.max_words {
+                if !top.search(0, &self.all_words, target_words) {
+                    break; // Cancelled
+                }
             }
         }
+        top.into_solutions()
+    }
 
-        // Sort by score descending
-        solutions.sort_by(|a, b| b.score.cmp(&a.score));
+    /// The highest-scoring of the solutions with the fewest words, or `None` if there are no solutions.
+    pub fn best_shortest_solution(&self) -> Option<Solution> {
+        self.best_shortest_after(Vec::new())
+    }
 
-        // Ensure we don't exceed max_solutions after sorting
-        solutions.truncate(self.max_solutions);
+    /// The highest-scoring of the shortest ways to finish a game which has already started with `played`.
+    /// Like `completions`, the solution holds just the words still to play.
+    pub fn best_completion(&self, played: &[&str]) -> Result<Option<Solution>, Error> {
+        Ok(self.best_shortest_after(self.played_path(played)?))
+    }
 
-        solutions
+    fn best_shortest_after(&self, played: Vec<usize>) -> Option<Solution> {
+        let first_words = self.first_words_after(&played);
+        let covered_bitmap = played.iter().fold(0, |acc, &i| acc | self.word_bitmaps[i].bitmap);
+
+        // Solutions come shortest first
+        let shortest = Solutions::new(self, played.clone(), first_words, self.min_words, self.max_words, None).next()?;
+        let mut top = TopSolutions::new(self, played, 1, None);
+        top.search(covered_bitmap, first_words, shortest.words.len());
+        top.into_solutions().into_iter().next()
+    }
+
+    /// Count the non-redundant solutions of each length, without building any of them.
+    ///
+    /// This ignores `max_solutions`, so it counts every solution between the minimum and maximum
+    /// number of words. It is much faster than collecting them, since the final word of each
+    /// solution is counted from a table rather than searched for.
+    pub fn count_solutions(&self) -> SolutionCounts {
+        let mut counter = SolutionCounter::new(self);
+        let mut counts = vec![0; self.max_words + 1];
+        for (target_words, count) in counts.iter_mut().enumerate().skip(self.min_words) {
+            *count = counter.count(0, &self.all_words, target_words);
+        }
+        SolutionCounts { counts }
+    }
+
+    /// Lazily iterate over solutions as the search finds them, shortest solutions first.
+    ///
+    /// Unlike `solve`, the solutions are not sorted by score, and `max_solutions` does not apply;
+    /// stop iterating (e.g. with `take`) once you have enough.
+    pub fn solutions(&self) -> Solutions<'_> {
+        self.solutions_cancellable(None)
+    }
+
+    /// Like `solutions`, but the iterator ends early once `cancel_flag` is set to true.
+    pub fn solutions_cancellable(&self, cancel_flag: Option<Arc<AtomicBool>>) -> Solutions<'_> {
+        Solutions::new(self, Vec::new(), &self.all_words, self.min_words, self.max_words, cancel_flag)
+    }
+
+    /// Carry on a search from where `cursor` says an earlier one got to, on the same board with the same
+    /// dictionary and word range. The first solution is the one the earlier search would have found next.
+    pub fn solutions_from(&self, cursor: &SearchCursor) -> Result<Solutions<'_>, Error> {
+        self.solutions_from_cancellable(cursor, None)
     }
 
-    fn search_recursive(
+    /// Like `solutions_from`, but the iterator ends early once `cancel_flag` is set to true.
+    pub fn solutions_from_cancellable(
         &self,
-        current_path: &mut Vec<Word>,
-        covered_bitmap: u32,
-        last_char: Option<char>,
-        solutions: &mut Vec<Solution>,
-        target_words: usize,
-        cancel_flag: Option<&Arc<AtomicBool>>,
-    ) -> bool // Returns true if not cancelled
-    {
-        // Check for cancellation
-        if let Some(flag) = cancel_flag {
-            if flag.load(Ordering::Relaxed) {
-                return false; // Cancelled
+        cursor: &SearchCursor,
+        cancel_flag: Option<Arc<AtomicBool>>,
+    ) -> Result<Solutions<'_>, Error> {
+        let mut solutions = self.solutions_cancellable(cancel_flag);
+        if !(self.min_words..=self.max_words).contains(&cursor.target_words) || !solutions.restore(cursor) {
+            return Err(Error::InvalidCursor {
+                cursor: cursor.to_string(),
+            });
+        }
+        Ok(solutions)
+    }
+
+    /// The next `n` solutions after `cursor`, or the first `n` without one, in the order the search finds
+    /// them, so shortest first but otherwise unsorted. The page says where to carry on from for the next.
+    pub fn solve_from(&self, cursor: Option<&SearchCursor>, n: usize) -> Result<SolutionPage, Error> {
+        let mut search = match cursor {
+            Some(cursor) => self.solutions_from(cursor)?,
+            None => self.solutions(),
+        };
+        let solutions = search.by_ref().take(n).collect();
+        Ok(SolutionPage {
+            solutions,
+            next: search.cursor(),
+        })
+    }
+
+    /// Ways to finish a game which has already started with the words in `played`, shortest first.
+    ///
+    /// Each solution holds just the words still to play, and is scored on those alone. The word range
+    /// counts them too, so with the default range this looks for up to 4 more words. A game that's
+    /// already finished has no completions.
+    pub fn completions(&self, played: &[&str]) -> Result<Solutions<'_>, Error> {
+        let path = self.played_path(played)?;
+        let first_words = self.first_words_after(&path);
+        Ok(Solutions::new(self, path, first_words, self.min_words, self.max_words, None))
+    }
+
+    /// The indices into `word_bitmaps` of words already played, which must chain together.
+    fn played_path(&self, played: &[&str]) -> Result<Vec<usize>, Error> {
+        let mut path: Vec<usize> = Vec::with_capacity(played.len());
+        for &text in played {
+            let word_idx = self
+                .word_bitmaps
+                .iter()
+                .position(|w| w.word.word == text)
+                .ok_or_else(|| Error::UnplayableWord { word: text.to_string() })?;
+            if let Some(&previous) = path.last() {
+                let previous = &self.word_bitmaps[previous];
+                if previous.last_char != self.word_bitmaps[word_idx].first_char {
+                    return Err(Error::BrokenChain {
+                        from: previous.word.word.clone(),
+                        to: text.to_string(),
+                    });
+                }
             }
+            path.push(word_idx);
         }
+        Ok(path)
+    }
 
-        // Early termination if we have enough solutions
-        if solutions.len() >= self.max_solutions {
-            return true;
+    /// The words which may come next after `path`: any word at all, if nothing's been played yet.
+    fn first_words_after(&self, path: &[usize]) -> &[usize] {
+        match path.last() {
+            Some(&last) => self.next_words(self.word_bitmaps[last].last_char),
+            None => &self.all_words,
         }
+    }
+
+    /// How many more words it takes to finish a game which has already started with `played`: 0 if it's
+    /// already finished, or `None` if it can't be finished within the word range.
+    pub fn fewest_words_to_finish(&self, played: &[&str]) -> Result<Option<usize>, Error> {
+        let mut completions = self.completions(played)?;
+        if completions.played_bitmap() == self.all_letters_mask {
+            return Ok(Some(0));
+        }
+        Ok(completions.next().map(|solution| solution.words.len()))
+    }
+
+    /// Solve using every CPU core, with the same results as `solve_cancellable`.
+    ///
+    /// Each possible first word is an independent branch of the search, so we hand those out to
+    /// a thread pool, a batch at a time. Keeping each batch's results in first-word order means
+    /// we collect exactly the solutions that the sequential search would have found first.
+    ///
+    /// Dead ends don't depend on the first word or the number of words, so each thread keeps one memo
+    /// for the whole search rather than starting afresh for every branch.
+    #[cfg(feature = "parallel")]
+    pub fn solve_parallel(&self, cancel_flag: Option<Arc<AtomicBool>>) -> Vec<Solution> {
+        use rayon::prelude::*;
+        use std::sync::Mutex;
+
+        let batch_size = rayon::current_num_threads() * 4;
+        let memos: Vec<Mutex<DeadEnds>> = (0..rayon::current_num_threads()).map(|_| Mutex::default()).collect();
+        let mut solutions = Vec::new();
 
-        // Check if we've found a complete solution of the target length
-        if covered_bitmap == self.all_letters_mask && current_path.len() == target_words {
-            let solution = Solution::new(current_path.clone());
-            if !self.is_solution_redundant(&solution) {
-                solutions.push(solution);
-                return true;
+        'search: for target_words in self.min_words..=self.max_words {
+            for batch in self.all_words.chunks(batch_size) {
+                let remaining = self.max_solutions - solutions.len();
+                let found: Vec<Vec<Solution>> = batch
+                    .par_chunks(1)
+                    .map(|first_word| {
+                        // Only this thread ever uses its memo, so the lock is never contended
+                        let thread = rayon::current_thread_index().unwrap_or(0);
+                        let mut memo = memos[thread].lock().unwrap();
+                        let mut search =
+                            Solutions::new(self, Vec::new(), first_word, target_words, target_words, cancel_flag.clone());
+                        search.dead_ends = std::mem::take(&mut *memo);
+                        let found = search.by_ref().take(remaining).collect();
+                        *memo = search.dead_ends;
+                        found
+                    })
+                    .collect();
+                solutions.extend(found.into_iter().flatten().take(remaining));
+
+                let cancelled = cancel_flag
+                    .as_ref()
+                    .is_some_and(|flag| flag.load(Ordering::Relaxed));
+                if cancelled || solutions.len() >= self.max_solutions {
+                    break 'search;
+                }
             }
         }
 
-        // Don't go deeper if we've hit the word limit
-        if current_path.len() >= target_words {
-            return true;
+        // Sort by score descending
+        solutions.sort_by_key(|s| Reverse(s.score));
+
+        solutions
+    }
+}
+
+/// Remembers which states of a search can still lead to a solution, so each dead end is only explored once.
+///
+/// A state is the letters covered so far, the letter the next word must start with, and how many words are
+/// left to play. Many paths reach the same state, e.g. "fog-glove" and "fig-glove" on some boards, and
+/// without this the search would try every possible ending after each of them.
+///
+/// This ignores redundancy, so a live state may still have no solutions, but a dead one never has any.
+#[derive(Debug, Default)]
+struct DeadEnds {
+    can_finish: HashMap<(char, u32, usize), bool>,
+}
+
+impl DeadEnds {
+    /// Could exactly `words_left` more words, starting with `next_char`, cover the rest of the board?
+    fn can_finish(&mut self, solver: &Solver, next_char: char, covered_bitmap: u32, words_left: usize) -> bool {
+        // Every word has to add new letters, so there's no room for more once everything is covered
+        if covered_bitmap == solver.all_letters_mask {
+            return words_left == 0;
+        }
+        if words_left == 0 {
+            return false;
         }
 
-        // Determine which words we can try next
-        let word_indices: Vec<usize> = if let Some(ch) = last_char {
-            // Must start with the last character of the previous word
-            self.words_by_first_letter
-                .get(&ch)
-                .map(|v| v.clone())
-                .unwrap_or_default()
+        let key = (next_char, covered_bitmap, words_left);
+        if let Some(&result) = self.can_finish.get(&key) {
+            return result;
+        }
+
+        let shapes = solver
+            .shapes_by_first_letter
+            .get(&next_char)
+            .map_or(&[][..], Vec::as_slice);
+        let result = if words_left == 1 {
+            let needed = solver.all_letters_mask & !covered_bitmap;
+            shapes.iter().any(|shape| shape.bitmap & needed == needed)
         } else {
-            // First word - can be any word
-            (0..self.word_bitmaps.len()).collect()
+            shapes.iter().any(|shape| {
+                let new_bitmap = covered_bitmap | shape.bitmap;
+                new_bitmap != covered_bitmap && self.can_finish(solver, shape.last_char, new_bitmap, words_left - 1)
+            })
+        };
+
+        self.can_finish.insert(key, result);
+        result
+    }
+}
+
+/// How many solutions a board has of each length, from `Solver::count_solutions`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolutionCounts {
+    counts: Vec<u64>, // indexed by number of words
+}
+
+impl SolutionCounts {
+    /// The number of solutions with exactly `words` words.
+    pub fn with_words(&self, words: usize) -> u64 {
+        self.counts.get(words).copied().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// The length of the shortest solution, if there are any solutions.
+    pub fn fewest_words(&self) -> Option<usize> {
+        self.counts.iter().position(|&count| count > 0)
+    }
+
+    /// Pairs of (number of words, number of solutions), for every length that was counted.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.counts.iter().copied().enumerate().skip(1)
+    }
+}
+
+/// Boards with more letters than this count their solutions one word at a time, since the tables
+/// `SolutionCounter` uses to count the final words all at once would grow too large.
+const MAX_COVERING_TABLE_LETTERS: u32 = 16;
+
+/// Paths whose final word has more ways than this to make the solution redundant count their final
+/// words one at a time, since inclusion-exclusion takes a step for every combination of those ways.
+const MAX_INCLUSION_EXCLUSION_SETS: usize = 20;
+
+/// Counts solutions for `Solver::count_solutions`.
+///
+/// Walking the search tree right down to every last word is far too slow for boards with millions of
+/// solutions. Instead, once only one word remains, we look up how many words could finish the chain
+/// in a table of how many words starting with each letter cover each set of letters.
+struct SolutionCounter<'a> {
+    solver: &'a Solver,
+    covering: Option<HashMap<char, Vec<u64>>>,
+    dead_ends: DeadEnds,
+    path: Vec<usize>,
+}
+
+impl<'a> SolutionCounter<'a> {
+    fn new(solver: &'a Solver) -> Self {
+        let letters = solver.all_letters_mask.count_ones();
+        let covering = (letters <= MAX_COVERING_TABLE_LETTERS).then(|| {
+            solver
+                .words_by_first_letter
+                .iter()
+                .map(|(&ch, word_indices)| {
+                    let mut counts = vec![0u64; 1 << letters];
+                    for &i in word_indices {
+                        counts[solver.word_bitmaps[i].bitmap as usize] += 1;
+                    }
+                    // Sum over supersets, so counts[mask] counts every word covering at least `mask`
+                    for bit in 0..letters {
+                        for mask in 0..counts.len() {
+                            if mask & (1 << bit) == 0 {
+                                counts[mask] += counts[mask | (1 << bit)];
+                            }
+                        }
+                    }
+                    (ch, counts)
+                })
+                .collect()
+        });
+
+        SolutionCounter {
+            solver,
+            covering,
+            dead_ends: DeadEnds::default(),
+            path: Vec::with_capacity(solver.max_words),
+        }
+    }
+
+    fn count(&mut self, covered_bitmap: u32, candidates: &'a [usize], target_words: usize) -> u64 {
+        let solver = self.solver;
+        let mut count = 0;
+        for &word_idx in candidates {
+            let word_bitmap = &solver.word_bitmaps[word_idx];
+            let new_bitmap = covered_bitmap | word_bitmap.bitmap;
+
+            // Only continue if this word adds new letters
+            if new_bitmap == covered_bitmap {
+                continue;
+            }
+
+            let words_left = target_words - self.path.len() - 1;
+            if words_left > 0 && !self.dead_ends.can_finish(solver, word_bitmap.last_char, new_bitmap, words_left) {
+                continue;
+            }
+
+            self.path.push(word_idx);
+            if self.path.len() == target_words {
+                if new_bitmap == solver.all_letters_mask && !solver.is_path_redundant(&self.path) {
+                    count += 1;
+                }
+            } else {
+                let last_words = if self.path.len() + 1 == target_words {
+                    self.count_last_words(new_bitmap, word_bitmap.last_char)
+                } else {
+                    None
+                };
+                count += last_words
+                    .unwrap_or_else(|| self.count(new_bitmap, solver.next_words(word_bitmap.last_char), target_words));
+            }
+            self.path.pop();
+        }
+        count
+    }
+
+    /// How many words starting with `last_char` would complete the current path into a non-redundant solution,
+    /// or `None` if they have to be counted one at a time.
+    fn count_last_words(&self, covered_bitmap: u32, last_char: char) -> Option<u64> {
+        let solver = self.solver;
+        let covering = self.covering.as_ref()?;
+        let (Some(covering), false) = (covering.get(&last_char), covered_bitmap == solver.all_letters_mask) else {
+            return Some(0);
+        };
+
+        // The final word has to cover all the letters we're missing...
+        let needed = solver.all_letters_mask & !covered_bitmap;
+
+        // ...but if it also covers everything missing from a redaction of the solution, the solution is redundant.
+        // Redactions without the final word can't cover everything, since the path so far doesn't.
+        let path = &self.path;
+        let last = path.len();
+        let chains = |i: usize, j: usize| {
+            let first_char = if j == last { last_char } else { solver.word_bitmaps[path[j]].first_char };
+            solver.word_bitmaps[path[i]].last_char == first_char
         };
+        let mut redundant_if_covering: Vec<u32> = redactable_masks(last + 1, chains)
+            .filter(|mask| mask & (1 << last) != 0)
+            .map(|mask| {
+                let kept = path
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| (mask & (1 << i)) != 0)
+                    .fold(0u32, |acc, (_, &idx)| acc | solver.word_bitmaps[idx].bitmap);
+                solver.all_letters_mask & !kept
+            })
+            .collect();
+
+        // Covering a superset of letters is implied by covering the subset, so only the smallest sets matter
+        redundant_if_covering.sort_by_key(|mask| mask.count_ones());
+        let mut minimal: Vec<u32> = Vec::new();
+        for mask in redundant_if_covering {
+            if minimal.iter().all(|&m| m & !mask != 0) {
+                minimal.push(mask);
+            }
+        }
+        if minimal.len() > MAX_INCLUSION_EXCLUSION_SETS {
+            return None;
+        }
+
+        // Inclusion-exclusion: words covering what we need, minus those which also cover any redaction's needs
+        let mut total: i64 = 0;
+        for subset in 0..(1usize << minimal.len()) {
+            let letters = minimal
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (subset & (1 << i)) != 0)
+                .fold(needed, |acc, (_, &m)| acc | m);
+            let words = covering[letters as usize] as i64;
+            if subset.count_ones() % 2 == 0 {
+                total += words;
+            } else {
+                total -= words;
+            }
+        }
+        Some(total as u64)
+    }
+}
+
+/// A solution found by `solve_best`, ordered so that the worst solution sits at the top of the heap.
+struct Ranked {
+    score: usize,
+    order: usize,
+    solution: Solution,
+}
+
+impl Ranked {
+    fn key(&self) -> (Reverse<usize>, usize) {
+        (Reverse(self.score), self.order)
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
 
-        for word_idx in word_indices {
-            let word_bitmap = &self.word_bitmaps[word_idx];
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// Branch-and-bound search state for `Solver::solve_best`.
+struct TopSolutions<'a> {
+    solver: &'a Solver,
+    played: usize, // words at the start of `path` which were played before the search began
+    limit: usize,
+    cancel_flag: Option<&'a Arc<AtomicBool>>,
+    best: BinaryHeap<Ranked>,
+    found: usize,
+    dead_ends: DeadEnds,
+    path: Vec<usize>,
+    prefix: Vec<&'a Word>,
+}
+
+impl<'a> TopSolutions<'a> {
+    fn new(solver: &'a Solver, played: Vec<usize>, limit: usize, cancel_flag: Option<&'a Arc<AtomicBool>>) -> Self {
+        TopSolutions {
+            solver,
+            played: played.len(),
+            limit,
+            cancel_flag,
+            best: BinaryHeap::new(),
+            found: 0,
+            dead_ends: DeadEnds::default(),
+            path: played,
+            prefix: Vec::new(),
+        }
+    }
+
+    /// Could a solution with this score make it into the top `limit`?
+    /// Later solutions lose ties, so they have to do strictly better than the worst we have.
+    fn can_beat(&self, score: usize) -> bool {
+        match self.best.peek() {
+            Some(worst) if self.best.len() >= self.limit => score > worst.score,
+            _ => true,
+        }
+    }
+
+    fn search(&mut self, covered_bitmap: u32, candidates: &'a [usize], target_words: usize) -> bool {
+        let solver = self.solver;
+        if self.cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            return false; // Cancelled
+        }
+
+        for &word_idx in candidates {
+            let word_bitmap = &solver.word_bitmaps[word_idx];
             let new_bitmap = covered_bitmap | word_bitmap.bitmap;
 
             // Only continue if this word adds new letters
-            if new_bitmap != covered_bitmap {
-                current_path.push(word_bitmap.word.clone());
-                let new_last_char = word_bitmap.word.word.chars().last();
-
-                if !self.search_recursive(
-                    current_path,
-                    new_bitmap,
-                    new_last_char,
-                    solutions,
-                    target_words,
-                    cancel_flag,
-                ) {
-                    current_path.pop();
-                    return false; // Cancelled
+            if new_bitmap == covered_bitmap {
+                continue;
+            }
+
+            let words_left = target_words - self.prefix.len() - 1;
+            if words_left > 0 && !self.dead_ends.can_finish(solver, word_bitmap.last_char, new_bitmap, words_left) {
+                continue;
+            }
+
+            self.path.push(word_idx);
+            self.prefix.push(&word_bitmap.word);
+
+            let promising = solver
+                .scorer
+                .upper_bound(&self.prefix, target_words)
+                .is_none_or(|bound| self.can_beat(bound));
+
+            let mut not_cancelled = true;
+            if promising {
+                if self.prefix.len() == target_words {
+                    if new_bitmap == solver.all_letters_mask {
+                        self.offer();
+                    }
+                } else {
+                    not_cancelled = self.search(new_bitmap, solver.next_words(word_bitmap.last_char), target_words);
+                }
+            }
+
+            self.path.pop();
+            self.prefix.pop();
+            if !not_cancelled {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Consider the complete path for a place in the top solutions.
+    fn offer(&mut self) {
+        let solver = self.solver;
+        if solver.is_redundant_after(&self.path, self.played) {
+            return;
+        }
+        let solution = solver.solution_for(&self.path[self.played..]);
+        if !self.can_beat(solution.score) {
+            return;
+        }
+
+        self.best.push(Ranked {
+            score: solution.score,
+            order: self.found,
+            solution,
+        });
+        self.found += 1;
+        if self.best.len() > self.limit {
+            self.best.pop();
+        }
+    }
+
+    fn into_solutions(self) -> Vec<Solution> {
+        // Sorted from least to greatest, and the greatest are the worst
+        self.best
+            .into_sorted_vec()
+            .into_iter()
+            .map(|ranked| ranked.solution)
+            .collect()
+    }
+}
+
+/// One level of the depth-first search: the words we may try next, and how far we've got through them.
+struct Frame<'a> {
+    covered_bitmap: u32,
+    candidates: &'a [usize],
+    next: usize,
+}
+
+/// Where a search got to, so that it can carry on from there later with `Solver::solutions_from`, even in
+/// another process.
+///
+/// It's written as the target word count, a colon, then how far the search had got through the words to try
+/// at each level, separated by dots, e.g. "2:118.40".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchCursor {
+    /// How many words the solutions being searched for have.
+    pub target_words: usize,
+    /// How many words the search had tried at each level, from the first word on. The last word tried at
+    /// each level but the deepest is the one the next level follows.
+    pub positions: Vec<usize>,
+}
+
+impl fmt::Display for SearchCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let positions: Vec<String> = self.positions.iter().map(usize::to_string).collect();
+        write!(f, "{}:{}", self.target_words, positions.join("."))
+    }
+}
+
+impl FromStr for SearchCursor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidCursor { cursor: s.to_string() };
+        let (target_words, positions) = s.split_once(':').ok_or_else(invalid)?;
+        let positions = if positions.is_empty() {
+            Vec::new()
+        } else {
+            positions
+                .split('.')
+                .map(|position| position.parse().map_err(|_| invalid()))
+                .collect::<Result<_, _>>()?
+        };
+        Ok(SearchCursor {
+            target_words: target_words.parse().map_err(|_| invalid())?,
+            positions,
+        })
+    }
+}
+
+/// Some of a board's solutions, from `Solver::solve_from`.
+#[derive(Debug, Clone)]
+pub struct SolutionPage {
+    pub solutions: Vec<Solution>,
+    /// Where to carry on from for more, or `None` if the search is finished.
+    pub next: Option<SearchCursor>,
+}
+
+/// How far `Solutions::advance` got.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchStep {
+    Found(Solution),
+    /// Ran out of steps before finding another solution. The search carries on from here next time.
+    Paused,
+    /// There are no more solutions, or the search was cancelled.
+    Finished,
+}
+
+/// Iterator over the solutions of a board, returned by `Solver::solutions`.
+///
+/// This is the same depth-first search as ever, searching for solutions of exactly 1, 2, 3 words and so on,
+/// but with an explicit stack so that it can stop after every solution it finds, or part way to the next one
+/// with `advance`.
+pub struct Solutions<'a> {
+    solver: &'a Solver,
+    cancel_flag: Option<Arc<AtomicBool>>,
+    played: usize, // words at the start of `path` which were played before the search began
+    played_bitmap: u32,
+    first_words: &'a [usize],
+    target_words: usize,
+    max_words: usize,
+    dead_ends: DeadEnds,
+    path: Vec<usize>,
+    stack: Vec<Frame<'a>>,
+}
+
+impl<'a> Solutions<'a> {
+    /// Search for solutions of `min_words` to `max_words` words, starting with one of `first_words`,
+    /// after the words already `played`.
+    fn new(
+        solver: &'a Solver,
+        played: Vec<usize>,
+        first_words: &'a [usize],
+        min_words: usize,
+        max_words: usize,
+        cancel_flag: Option<Arc<AtomicBool>>,
+    ) -> Self {
+        let played_bitmap = played.iter().fold(0, |acc, &i| acc | solver.word_bitmaps[i].bitmap);
+        let mut solutions = Solutions {
+            solver,
+            cancel_flag,
+            played: played.len(),
+            played_bitmap,
+            first_words,
+            target_words: min_words,
+            max_words,
+            dead_ends: DeadEnds::default(),
+            path: played,
+            stack: Vec::new(),
+        };
+        solutions.push_root();
+        solutions
+    }
+
+    fn push_root(&mut self) {
+        self.stack.push(Frame {
+            covered_bitmap: self.played_bitmap,
+            candidates: self.first_words,
+            next: 0,
+        });
+    }
+
+    /// The letters covered by the words played before the search began.
+    fn played_bitmap(&self) -> u32 {
+        self.played_bitmap
+    }
+
+    /// How many words the solutions being searched for right now have. This only goes up, so once it's
+    /// past a length, every solution of that length has been found.
+    pub fn target_words(&self) -> usize {
+        self.target_words
+    }
+
+    /// Where the search has got to, or `None` if it's finished. A cancelled search can carry on from here too.
+    pub fn cursor(&self) -> Option<SearchCursor> {
+        if self.stack.is_empty() && self.target_words >= self.max_words {
+            return None;
+        }
+        Some(SearchCursor {
+            target_words: self.target_words,
+            positions: self.stack.iter().map(|frame| frame.next).collect(),
+        })
+    }
+
+    /// Put the search back where `cursor` says, rebuilding the path it had taken from the positions. Returns
+    /// false if the cursor can't have come from this search.
+    fn restore(&mut self, cursor: &SearchCursor) -> bool {
+        // There's a level for the first word, and one for each word after it up to the target
+        if cursor.positions.len() > cursor.target_words {
+            return false;
+        }
+
+        self.target_words = cursor.target_words;
+        self.stack.clear();
+        self.path.truncate(self.played);
+        for &next in &cursor.positions {
+            let (covered_bitmap, candidates) = match self.stack.last() {
+                None => (self.played_bitmap(), self.first_words),
+                Some(parent) => {
+                    let Some(&word_idx) = parent.next.checked_sub(1).and_then(|i| parent.candidates.get(i)) else {
+                        return false;
+                    };
+                    let word_bitmap = &self.solver.word_bitmaps[word_idx];
+                    self.path.push(word_idx);
+                    (parent.covered_bitmap | word_bitmap.bitmap, self.solver.next_words(word_bitmap.last_char))
+                }
+            };
+            if next > candidates.len() {
+                return false;
+            }
+            self.stack.push(Frame {
+                covered_bitmap,
+                candidates,
+                next,
+            });
+        }
+        true
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+}
+
+impl Solutions<'_> {
+    /// Search until the next solution, but give up after trying `max_steps` words, so that a caller which
+    /// can't block for long (like the browser) can do a little at a time.
+    pub fn advance(&mut self, max_steps: usize) -> SearchStep {
+        let solver = self.solver;
+        for _ in 0..max_steps {
+            // Leave the stack be, so that the search can carry on from its cursor
+            if self.is_cancelled() {
+                return SearchStep::Finished;
+            }
+
+            let Some(frame) = self.stack.last_mut() else {
+                // Finished every path of this length, so try longer solutions
+                if self.target_words >= self.max_words {
+                    return SearchStep::Finished;
+                }
+                self.target_words += 1;
+                self.push_root();
+                continue;
+            };
+
+            let Some(&word_idx) = frame.candidates.get(frame.next) else {
+                // Exhausted this level; backtrack. The root level has no word of its own on the path.
+                self.stack.pop();
+                if !self.stack.is_empty() {
+                    self.path.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+
+            let word_bitmap = &solver.word_bitmaps[word_idx];
+            let new_bitmap = frame.covered_bitmap | word_bitmap.bitmap;
+
+            // Only continue if this word adds new letters
+            if new_bitmap == frame.covered_bitmap {
+                continue;
+            }
+
+            self.path.push(word_idx);
+
+            // Don't go deeper if we've hit the word limit
+            let words_found = self.path.len() - self.played;
+            if words_found == self.target_words {
+                let found = (new_bitmap == solver.all_letters_mask
+                    && !solver.is_redundant_after(&self.path, self.played))
+                .then(|| solver.solution_for(&self.path[self.played..]));
+                self.path.pop();
+                if let Some(solution) = found {
+                    return SearchStep::Found(solution);
                 }
+                continue;
+            }
 
-                current_path.pop();
+            // Skip the whole subtree if no ending could complete it
+            let words_left = self.target_words - words_found;
+            if !self.dead_ends.can_finish(solver, word_bitmap.last_char, new_bitmap, words_left) {
+                self.path.pop();
+                continue;
             }
+
+            // Must start with the last character of the previous word
+            self.stack.push(Frame {
+                covered_bitmap: new_bitmap,
+                candidates: solver.next_words(word_bitmap.last_char),
+                next: 0,
+            });
         }
+        SearchStep::Paused
+    }
+}
 
-        true // Not cancelled
+impl Iterator for Solutions<'_> {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Solution> {
+        loop {
+            match self.advance(usize::MAX) {
+                SearchStep::Found(solution) => return Some(solution),
+                SearchStep::Paused => continue,
+                SearchStep::Finished => return None,
+            }
+        }
     }
 }
 
@@ -311,6 +1156,27 @@ mod tests {
         assert_eq!(single_word.to_string(), "word");
     }
 
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = SearchCursor {
+            target_words: 2,
+            positions: vec![118, 40],
+        };
+        assert_eq!(cursor.to_string(), "2:118.40");
+        assert_eq!("2:118.40".parse::<SearchCursor>().unwrap(), cursor);
+        assert_eq!("3:".parse::<SearchCursor>().unwrap().positions, Vec::<usize>::new());
+        assert!("2".parse::<SearchCursor>().is_err());
+        assert!("2:1.x".parse::<SearchCursor>().is_err());
+    }
+
+    #[test]
+    fn test_newly_covered() {
+        let words = ["foxglove", "eye", "equity"];
+        let dictionary = Dictionary::from_strings(words.iter().map(|&s| s.to_string()).collect());
+        let solution = Solution::new(dictionary.words.clone());
+        assert_eq!(solution.newly_covered(), vec!["foxglve", "y", "quit"]);
+    }
+
     #[test]
     fn test_redactable_subsequences() {
         let words = ["foxglove", "eye", "equity"];
@@ -334,7 +1200,7 @@ mod tests {
 
         // Helper to check if specific indices are in redactions
         let has_redaction = |expected_indices: Vec<usize>| {
-            redaction_indices.iter().any(|r| *r == expected_indices)
+            redaction_indices.contains(&expected_indices)
         };
 
         assert!(has_redaction(vec![1, 2]), "Should have [1, 2] = EYE-EQUITY (removes head)");
@@ -385,7 +1251,7 @@ mod tests {
         let solver = Solver::new(board, &dictionary, 1000);
         let solutions = solver.solve();
 
-        fn has(solutions: &Vec<Solution>, ws: Vec<&Word>) -> bool {
+        fn has(solutions: &[Solution], ws: Vec<&Word>) -> bool {
             let vec_word_clones: Vec<Word> = ws.iter().map(|&w| w.clone()).collect();
             let solution = Solution::new(vec_word_clones);
             solutions.contains(&solution)
@@ -418,15 +1284,6 @@ mod tests {
         ];
         let game = Board::from_sides(sides).unwrap();
 
-        // Create a simple wordlist that includes all the digraphs these words need
-        // Since the game is AB/CD/EF/GH, we need valid cross-side digraphs
-        let mut valid_digraphs = std::collections::HashSet::new();
-
-        // Add all valid digraphs from the game (cross-side pairs)
-        for digraph in &game.digraphs {
-            valid_digraphs.insert(digraph.clone());
-        }
-
         // For our test words, we need to pick ones that use valid cross-side digraphs
         // Let's use simpler words that work: "AC" (A->C), "CE" (C->E), etc.
         let test_words = ["ac", "ce", "eg"];
@@ -454,6 +1311,105 @@ mod tests {
         }
 
         // Test that basic bitmap operations work
-        assert!(solver.word_bitmaps.len() > 0);
+        assert!(!solver.word_bitmaps.is_empty());
+    }
+
+    #[test]
+    fn test_bitmap_coverage_full_alphabet() {
+        let sides = vec![
+            "abcde".to_string(),
+            "fghij".to_string(),
+            "klmno".to_string(),
+            "pqrst".to_string(),
+            "uvw".to_string(),
+            "xyz".to_string(),
+        ];
+        let game = Board::from_sides(sides).unwrap();
+
+        // Not real words, but each one bounces between sides, and together they cover the alphabet
+        let test_words = ["afkpuxbglqvyc", "chmrwzdinsejot"];
+        let test_word_strings = test_words.iter().map(|&s| s.to_string()).collect();
+        let dictionary = Dictionary::from_strings(test_word_strings);
+        let solver = Solver::new(game, &dictionary, 10);
+
+        assert_eq!(solver.all_letters_mask, (1 << 26) - 1);
+
+        let word_z = solver.word_bitmaps.iter().find(|wb| wb.word.word == "chmrwzdinsejot").unwrap();
+        // Z is the last letter on the board, so it gets the highest bit
+        assert_ne!(word_z.bitmap & (1 << 25), 0);
+
+        let solutions = solver.solve();
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(solutions[0].to_string(), "afkpuxbglqvyc-chmrwzdinsejot");
+    }
+
+    #[test]
+    fn test_dead_ends() {
+        let game = Board::from_sides(vec!["ab".to_string(), "cd".to_string(), "ef".to_string(), "gh".to_string()]).unwrap();
+
+        // The only way to cover every letter is ace-eg-gbd-df-fh
+        let words = ["ace", "eg", "gbd", "df", "fh"];
+        let dictionary = Dictionary::from_strings(words.iter().map(|w| w.to_string()).collect());
+        let solver = Solver::new(game, &dictionary, 10);
+        let ace = solver.word_bitmaps.iter().find(|wb| wb.word.word == "ace").unwrap().bitmap;
+
+        let mut dead_ends = DeadEnds::default();
+        assert!(dead_ends.can_finish(&solver, 'e', ace, 4));
+        assert!(!dead_ends.can_finish(&solver, 'e', ace, 3));
+        assert!(!dead_ends.can_finish(&solver, 'e', ace, 5));
+        assert!(!dead_ends.can_finish(&solver, 'f', ace, 4));
+
+        // Answers are remembered
+        assert_eq!(dead_ends.can_finish.get(&('e', ace, 4)), Some(&true));
+        assert!(dead_ends.can_finish(&solver, 'e', ace, 4));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_solve_parallel_matches_sequential() {
+        let sides = vec![
+            "vyq".to_string(),
+            "fig".to_string(),
+            "ote".to_string(),
+            "xlu".to_string(),
+        ];
+        let board = Board::from_sides(sides).unwrap();
+
+        let word_strs = ["foxglove", "equity", "eye", "golf", "flog", "glove", "exile", "exit", "tie", "yog"];
+        let word_strings = word_strs.iter().map(|&s| s.to_string()).collect();
+        let dictionary = Dictionary::from_strings(word_strings);
+
+        for max_solutions in [1, 2, 5, 1000] {
+            let solver = Solver::new(board.clone(), &dictionary, max_solutions);
+            assert_eq!(solver.solve_parallel(None), solver.solve());
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_solve_parallel_stops_part_way_through_a_batch() {
+        let sides = vec![
+            "vyq".to_string(),
+            "fig".to_string(),
+            "ote".to_string(),
+            "xlu".to_string(),
+        ];
+        let board = Board::from_sides(sides).unwrap();
+
+        let word_strs = [
+            "foxglove", "equity", "eye", "golf", "flog", "glove", "exile", "exit", "tie", "yog", "gluey", "envoy",
+            "yet", "toxify", "quote",
+        ];
+        let word_strings = word_strs.iter().map(|&s| s.to_string()).collect();
+        let dictionary = Dictionary::from_strings(word_strings);
+        let total = Solver::new(board.clone(), &dictionary, u16::MAX).solve().len();
+        assert!(total > 2);
+
+        // Two threads take batches of eight first words, so most of these limits land part way through one
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        for max_solutions in 1..=total as u16 {
+            let solver = Solver::new(board.clone(), &dictionary, max_solutions);
+            assert_eq!(pool.install(|| solver.solve_parallel(None)), solver.solve());
+        }
     }
 }
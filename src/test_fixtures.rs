@@ -0,0 +1,30 @@
+//! Boards and dictionaries shared by the unit tests.
+
+use crate::board::Board;
+use crate::dictionary::Dictionary;
+
+pub fn board_from_strs(sides: &[&str]) -> Board {
+    Board::from_sides(sides.iter().map(|s| s.to_string()).collect()).unwrap()
+}
+
+/// A board where "foxglove-equity" is a solution.
+pub fn board() -> Board {
+    board_from_strs(&["vyq", "fig", "ote", "xlu"])
+}
+
+/// Some words for `board`, including a few which can't be played on it.
+pub fn dictionary() -> Dictionary {
+    let words = ["foxglove", "equity", "fox", "exile", "quit", "fig", "elf", "you", "tye", "ace", "cab", "vex"];
+    Dictionary::from_strings(words.iter().map(|w| w.to_string()).collect())
+}
+
+/// The board from the README, where "forklift-twangy" is the best solution.
+pub fn forklift_board() -> Board {
+    board_from_strs(&["yfa", "otk", "lgw", "rni"])
+}
+
+/// Just enough words for `forklift_board` to have a couple of ways to finish from "fork".
+pub fn forklift_dictionary() -> Dictionary {
+    let words = ["forklift", "twangy", "fork", "kilt", "tangy"];
+    Dictionary::from_strings(words.iter().map(|w| w.to_string()).collect())
+}
@@ -1,18 +1,25 @@
 use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::future_to_promise;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
 use crate::board::Board;
 use crate::dictionary::Dictionary;
-use crate::solver::Solver;
+use crate::hint::{HintLevel, Hints};
+use crate::playable::PlayableIndex;
+use crate::solver::{SearchCursor, SearchStep, Solution, Solutions, Solver};
+use serde::Serialize;
 use std::sync::{OnceLock, Mutex};
+use std::cmp::Reverse;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use js_sys::Promise;
+use js_sys::{Date, Function, Promise};
 
 // Import the `console.log` function from the browser's Web API
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     fn log(s: &str);
+
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout_ms: i32) -> JsValue;
 }
 
 // Define a macro to make console logging easier
@@ -24,24 +31,94 @@ macro_rules! console_log {
 static GLOBAL_DICTIONARY: OnceLock<Arc<Dictionary>> = OnceLock::new();
 
 // Current solve task state
-#[derive(Clone, PartialEq)]
-struct SolveParams {
-    sides: Vec<String>,
-    max_solutions: u16,
-}
-
 struct SolveTask {
-    params: SolveParams,
     cancel_flag: Arc<AtomicBool>,
 }
 
 static CURRENT_SOLVE: OnceLock<Mutex<Option<SolveTask>>> = OnceLock::new();
 
+// The playable words for the last board solved. Boards are usually edited a letter at a time, so this
+// is cheap to update for the next one.
+static PLAYABLE_INDEX: OnceLock<Mutex<Option<PlayableIndex<'static>>>> = OnceLock::new();
+
+/// A solution as `solve_game` hands it to JavaScript.
+#[derive(Serialize)]
+struct SolutionObject {
+    words: Vec<SolutionWord>,
+    score: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SolutionWord {
+    word: String,
+    frequency: i8,
+    /// The letters this word covers which no earlier word in the solution did.
+    new_letters: String,
+}
+
+/// How the search is going, reported with each batch of solutions.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SolveProgress {
+    /// How many words the solutions being searched for have
+    target_words: usize,
+    /// Solutions found so far, including this batch
+    found: usize,
+    elapsed_ms: f64,
+}
+
+/// What `solve_game` passes to its `on_batch` callback: the solutions found since the last batch.
+#[derive(Serialize)]
+struct SolveBatch {
+    solutions: Vec<SolutionObject>,
+    progress: SolveProgress,
+}
+
+/// What `solve_game` and `solve_page` resolve to.
+#[derive(Serialize)]
+struct SolutionPageObject {
+    solutions: Vec<SolutionObject>,
+    /// Where to carry on from for the next page, or `None` once there are no more
+    cursor: Option<String>,
+}
+
+/// How often `solve_game` hands over a batch of solutions while it searches, in milliseconds.
+const BATCH_INTERVAL_MS: f64 = 100.0;
+/// How long `solve_game` searches before giving the worker a chance to handle other messages, such as a
+/// cancel or a new board, in milliseconds. Browsers make a timeout wait at least 4ms once they're nested,
+/// so much shorter slices would spend a lot of their time waiting.
+const SLICE_MS: f64 = 20.0;
+/// How many words the search tries between looks at the clock.
+const STEPS_PER_CHECK: usize = 1000;
+
+impl From<&Solution> for SolutionObject {
+    fn from(solution: &Solution) -> Self {
+        let words = solution
+            .words
+            .iter()
+            .zip(solution.newly_covered())
+            .map(|(word, new_letters)| SolutionWord {
+                word: word.word.clone(),
+                frequency: word.frequency,
+                new_letters,
+            })
+            .collect();
+        SolutionObject {
+            words,
+            score: solution.score,
+        }
+    }
+}
+
 #[wasm_bindgen]
 pub fn initialize_dictionary(dictionary_data: Vec<u8>) -> Result<(), String> {
     console_log!("Initializing global dictionary from {} bytes", dictionary_data.len());
 
-    let dictionary = Dictionary::from_bytes(&dictionary_data)?;
+    let (dictionary, malformed) = Dictionary::from_bytes(&dictionary_data).map_err(|e| e.to_string())?;
+    for e in malformed {
+        console_log!("Skipping dictionary line. {}", e);
+    }
     console_log!("Parsed dictionary with {} words", dictionary.words.len());
 
     // Initialize the current solve tracker
@@ -56,114 +133,277 @@ pub fn initialize_dictionary(dictionary_data: Vec<u8>) -> Result<(), String> {
     }
 }
 
+/// Solve a board. The promise resolves to `{ solutions, cursor }`, where the solutions are the first
+/// `max_solutions` found, best first, each a plain object like `{ words: [{ word, frequency, newLetters }],
+/// score }`. If the search stopped there, `cursor` says where it got to, for `solve_page` to carry on from.
+///
+/// While the search goes on, `on_batch` (if given) is called every so often with the solutions found since
+/// it was last called, in the order they were found, as `{ solutions, progress: { targetWords, found,
+/// elapsedMs } }`. It's called on time even when nothing new has been found, with no solutions, so that
+/// long stretches without any still report progress. The last batch comes just before the promise resolves.
+///
+/// The search runs a few milliseconds at a time, so that `cancel_current_solve`, or another call to
+/// `solve_game` or `solve_page`, can stop it part way. Either makes the promise reject with "Cancelled".
 #[wasm_bindgen]
-pub fn solve_game(game_sides: Vec<String>, max_solutions: u16) -> Promise {
+pub fn solve_game(
+    game_sides: Vec<String>,
+    max_solutions: u16,
+    min_words: usize,
+    max_words: usize,
+    on_batch: Option<Function>,
+) -> Promise {
     console_log!("Solve requested with {} sides", game_sides.len());
 
     future_to_promise(async move {
-        // Check if dictionary is initialized
-        let dictionary = match GLOBAL_DICTIONARY.get() {
-            Some(dict) => dict,
-            None => {
-                console_log!("Error: Dictionary not initialized");
-                return Err(JsValue::from_str("Dictionary not initialized"));
-            }
-        };
+        let (solver, cancel_flag) = start_solve(game_sides, max_solutions, min_words, max_words)?;
+        let search = solver.solutions_cancellable(Some(cancel_flag.clone()));
+        let (mut solutions, cursor) = run_search(search, max_solutions, &cancel_flag, on_batch.as_ref()).await?;
 
-        let new_params = SolveParams {
-            sides: game_sides.clone(),
-            max_solutions,
-        };
+        // Best first
+        solutions.sort_by_key(|s| Reverse(s.score));
+        page_to_js(&solutions, cursor)
+    })
+}
 
-        // Check if we need to cancel an existing solve
-        let cancel_flag = if let Some(solve_mutex) = CURRENT_SOLVE.get() {
-            let mut current = solve_mutex.lock().unwrap();
-
-            // If there's a current task with different params, cancel it
-            if let Some(ref task) = *current {
-                if task.params != new_params {
-                    console_log!("Cancelling previous solve with different params");
-                    task.cancel_flag.store(true, Ordering::Relaxed);
-                } else {
-                    console_log!("Solve already in progress with same params, rejecting duplicate");
-                    return Err(JsValue::from_str("Solve already in progress"));
+/// Carry on a search from `cursor`, which came from `solve_game` or an earlier page, for up to `page_size`
+/// more solutions. The promise resolves to `{ solutions, cursor }` like `solve_game`'s, but with the
+/// solutions in the order the search found them. Without a cursor, this starts from the beginning.
+///
+/// The board, `min_words` and `max_words` must be the same as for the search the cursor came from. Like
+/// `solve_game`, this runs a few milliseconds at a time and can be cancelled the same way.
+#[wasm_bindgen]
+pub fn solve_page(
+    game_sides: Vec<String>,
+    cursor: Option<String>,
+    page_size: u16,
+    min_words: usize,
+    max_words: usize,
+) -> Promise {
+    future_to_promise(async move {
+        let cursor = match cursor {
+            Some(cursor) => Some(cursor.parse::<SearchCursor>().map_err(|e| JsValue::from_str(&e.to_string()))?),
+            None => None,
+        };
+        let (solver, cancel_flag) = start_solve(game_sides, page_size, min_words, max_words)?;
+        let search = match &cursor {
+            Some(cursor) => match solver.solutions_from_cancellable(cursor, Some(cancel_flag.clone())) {
+                Ok(search) => search,
+                Err(e) => {
+                    finish_solve(&cancel_flag);
+                    return Err(JsValue::from_str(&e.to_string()));
                 }
-            }
-
-            // Create new cancel flag and task
-            let cancel_flag = Arc::new(AtomicBool::new(false));
-            *current = Some(SolveTask {
-                params: new_params.clone(),
-                cancel_flag: cancel_flag.clone(),
-            });
-
-            cancel_flag
-        } else {
-            console_log!("Error: CURRENT_SOLVE not initialized");
-            return Err(JsValue::from_str("Solver not initialized"));
+            },
+            None => solver.solutions_cancellable(Some(cancel_flag.clone())),
         };
+        let (solutions, cursor) = run_search(search, page_size, &cancel_flag, None).await?;
+        page_to_js(&solutions, cursor)
+    })
+}
 
-        // Create the board
-        let board = match Board::from_sides(game_sides) {
-            Ok(board) => board,
-            Err(e) => {
-                console_log!("Error creating board: {}", e);
-
-                // Clear current task since we failed
-                if let Some(solve_mutex) = CURRENT_SOLVE.get() {
-                    *solve_mutex.lock().unwrap() = None;
-                }
+/// Make this the current solve, cancelling any other, and build its solver from the playable words of the
+/// last board solved, updated for this one.
+fn start_solve(
+    game_sides: Vec<String>,
+    max_solutions: u16,
+    min_words: usize,
+    max_words: usize,
+) -> Result<(Solver, Arc<AtomicBool>), JsValue> {
+    // Check if dictionary is initialized
+    let dictionary = match GLOBAL_DICTIONARY.get() {
+        Some(dict) => dict,
+        None => {
+            console_log!("Error: Dictionary not initialized");
+            return Err(JsValue::from_str("Dictionary not initialized"));
+        }
+    };
 
-                return Err(JsValue::from_str(&e.to_string()));
-            }
-        };
+    if min_words < 1 || min_words > max_words {
+        return Err(JsValue::from_str(&format!("Invalid word range {}..={}", min_words, max_words)));
+    }
 
-        // Clone the Arc (cheap) for the async task
-        let dictionary_arc = dictionary.clone();
+    // A new solve replaces any still in progress, which stops when it next wakes up
+    let cancel_flag = if let Some(solve_mutex) = CURRENT_SOLVE.get() {
+        let mut current = solve_mutex.lock().unwrap();
+        if let Some(ref task) = *current {
+            console_log!("Cancelling previous solve");
+            task.cancel_flag.store(true, Ordering::Relaxed);
+        }
 
-        console_log!("Starting solve task");
+        // Create new cancel flag and task
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        *current = Some(SolveTask {
+            cancel_flag: cancel_flag.clone(),
+        });
 
-        let solver = Solver::new(board, &dictionary_arc, max_solutions);
-        let solutions = solver.solve_cancellable(Some(cancel_flag.clone()));
+        cancel_flag
+    } else {
+        console_log!("Error: CURRENT_SOLVE not initialized");
+        return Err(JsValue::from_str("Solver not initialized"));
+    };
 
-        // Check if we were cancelled
-        if cancel_flag.load(Ordering::Relaxed) {
-            console_log!("Solve was cancelled");
+    // Create the board
+    let board = match Board::from_sides(game_sides) {
+        Ok(board) => board,
+        Err(e) => {
+            console_log!("Error creating board: {}", e);
 
-            // Clear current task
+            // Clear current task since we failed
             if let Some(solve_mutex) = CURRENT_SOLVE.get() {
-                let mut current = solve_mutex.lock().unwrap();
-                if let Some(ref task) = *current {
-                    if Arc::ptr_eq(&task.cancel_flag, &cancel_flag) {
-                        *current = None;
-                    }
-                }
+                *solve_mutex.lock().unwrap() = None;
             }
 
-            return Err(JsValue::from_str("Cancelled"));
+            return Err(JsValue::from_str(&e.to_string()));
         }
+    };
 
-        console_log!("Found {} solutions", solutions.len());
+    console_log!("Starting solve task");
 
-        // Convert solutions to JS array
-        let js_array = js_sys::Array::new();
-        for solution in &solutions {
-            let solution_str = format!("{}:{}", solution.to_string(), solution.score);
-            js_array.push(&JsValue::from_str(&solution_str));
+    let mut cached = PLAYABLE_INDEX.get_or_init(|| Mutex::new(None)).lock().unwrap();
+    let index = match cached.as_mut() {
+        Some(index) => {
+            index.set_board(board);
+            index
         }
+        None => cached.insert(PlayableIndex::new(board, dictionary)),
+    };
+    let solver = Solver::from_index(index, max_solutions).with_word_range(min_words, max_words);
+    Ok((solver, cancel_flag))
+}
 
-        // Clear current task
-        if let Some(solve_mutex) = CURRENT_SOLVE.get() {
-            let mut current = solve_mutex.lock().unwrap();
-            if let Some(ref task) = *current {
-                if Arc::ptr_eq(&task.cancel_flag, &cancel_flag) {
-                    *current = None;
-                }
+/// Take up to `max_solutions` solutions from `search`, a slice of time at a time, passing batches to
+/// `on_batch` if there is one. Returns them in the order they were found, with where the search got to,
+/// unless it's finished.
+async fn run_search(
+    mut search: Solutions<'_>,
+    max_solutions: u16,
+    cancel_flag: &Arc<AtomicBool>,
+    on_batch: Option<&Function>,
+) -> Result<(Vec<Solution>, Option<SearchCursor>), JsValue> {
+    let started = Date::now();
+    let mut slice_start = started;
+    let mut last_batch = started;
+    let mut batch_start = 0;
+    let mut solutions = Vec::new();
+
+    while solutions.len() < usize::from(max_solutions) {
+        match search.advance(STEPS_PER_CHECK) {
+            SearchStep::Found(solution) => solutions.push(solution),
+            SearchStep::Paused => {}
+            SearchStep::Finished => break,
+        }
+
+        // The search pauses every few steps, so the clock is checked whether or not it found anything
+        let now = Date::now();
+        if now - last_batch >= BATCH_INTERVAL_MS {
+            if let Err(e) = send_batch(on_batch, &solutions[batch_start..], &search, solutions.len(), now - started) {
+                finish_solve(cancel_flag);
+                return Err(e);
             }
+            batch_start = solutions.len();
+            last_batch = now;
+        }
+        if now - slice_start >= SLICE_MS {
+            yield_to_event_loop().await;
+            slice_start = Date::now();
         }
+    }
 
-        Ok(js_array.into())
-    })
+    // Check if we were cancelled
+    if cancel_flag.load(Ordering::Relaxed) {
+        console_log!("Solve was cancelled");
+        finish_solve(cancel_flag);
+        return Err(JsValue::from_str("Cancelled"));
+    }
+
+    console_log!("Found {} solutions", solutions.len());
+    let sent = send_batch(on_batch, &solutions[batch_start..], &search, solutions.len(), Date::now() - started);
+    finish_solve(cancel_flag);
+    sent?;
+    Ok((solutions, search.cursor()))
+}
+
+/// Convert solutions to an array of plain objects, with the cursor to carry on from.
+fn page_to_js(solutions: &[Solution], cursor: Option<SearchCursor>) -> Result<JsValue, JsValue> {
+    let page = SolutionPageObject {
+        solutions: solutions.iter().map(SolutionObject::from).collect(),
+        cursor: cursor.map(|cursor| cursor.to_string()),
+    };
+    serde_wasm_bindgen::to_value(&page).map_err(JsValue::from)
+}
+
+/// Call `on_batch`, if there is one, with `batch`, the latest solutions from `search`.
+fn send_batch(
+    on_batch: Option<&Function>,
+    batch: &[Solution],
+    search: &Solutions,
+    found: usize,
+    elapsed_ms: f64,
+) -> Result<(), JsValue> {
+    let Some(on_batch) = on_batch else {
+        return Ok(());
+    };
+    let batch = SolveBatch {
+        solutions: batch.iter().map(SolutionObject::from).collect(),
+        progress: SolveProgress {
+            target_words: search.target_words(),
+            found,
+            elapsed_ms,
+        },
+    };
+    on_batch.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(&batch)?)?;
+    Ok(())
+}
+
+/// Let the worker handle any messages waiting for it before carrying on.
+async fn yield_to_event_loop() {
+    let timeout = Promise::new(&mut |resolve, _reject| {
+        set_timeout(&resolve, 0);
+    });
+    // A timeout can't fail
+    let _ = JsFuture::from(timeout).await;
+}
+
+/// Forget the current solve, unless it's already been replaced by a newer one.
+fn finish_solve(cancel_flag: &Arc<AtomicBool>) {
+    if let Some(solve_mutex) = CURRENT_SOLVE.get() {
+        let mut current = solve_mutex.lock().unwrap();
+        if let Some(ref task) = *current {
+            if Arc::ptr_eq(&task.cancel_flag, cancel_flag) {
+                *current = None;
+            }
+        }
+    }
+}
+
+/// Check a player's answer, returning the `AnswerCheck` as a plain object.
+#[wasm_bindgen]
+pub fn check_answer(game_sides: Vec<String>, words: Vec<String>) -> Result<JsValue, JsValue> {
+    let dictionary = GLOBAL_DICTIONARY
+        .get()
+        .ok_or_else(|| JsValue::from_str("Dictionary not initialized"))?;
+    let board = Board::from_sides(game_sides).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let check = board.check_answer(&words, dictionary);
+    serde_wasm_bindgen::to_value(&check).map_err(JsValue::from)
+}
+
+/// The hints up to `level` (1 to 5) for a board, each as a plain object, or undefined if the board has no
+/// solutions of `max_words` words or fewer.
+#[wasm_bindgen]
+pub fn get_hints(game_sides: Vec<String>, level: u8, max_words: usize) -> Result<JsValue, JsValue> {
+    let dictionary = GLOBAL_DICTIONARY
+        .get()
+        .ok_or_else(|| JsValue::from_str("Dictionary not initialized"))?;
+    let level = HintLevel::from_number(level).ok_or_else(|| JsValue::from_str(&format!("Invalid hint level {}", level)))?;
+    if max_words < 1 {
+        return Err(JsValue::from_str("max_words must be at least 1"));
+    }
+    let board = Board::from_sides(game_sides).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let solver = Solver::new(board, dictionary, 1).with_word_range(Solver::DEFAULT_MIN_WORDS, max_words);
+    let hints = Hints::new(&solver).map(|hints| hints.up_to(level));
+    serde_wasm_bindgen::to_value(&hints).map_err(JsValue::from)
 }
 
 #[wasm_bindgen]
@@ -1,4 +1,5 @@
-use letter_bounced::board::Board;
+use letter_bounced::board::{Board, Side};
+use letter_bounced::Error;
 
 mod common;
 use common::sides_from_strs;
@@ -15,20 +16,50 @@ fn test_valid_game() {
 
 #[test]
 fn test_invalid_number_of_sides() {
-    let sides = sides_from_strs(&["abc", "def", "ghi"]); // Only 3 sides
+    let sides = sides_from_strs(&["abc", "def"]); // Only 2 sides
     let result = Board::from_sides(sides);
 
-    assert!(result.is_err());
-    assert!(result.unwrap_err().to_string().contains("exactly 4 sides"));
+    assert!(matches!(result, Err(Error::WrongSideCount { found: 2 })));
+    assert!(result.unwrap_err().to_string().contains("at least 3 sides"));
+}
+
+#[test]
+fn test_triangle_game() {
+    let sides = sides_from_strs(&["abc", "def", "ghi"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    assert_eq!(game.sides.len(), 3);
+    assert_eq!(game.digraphs.len(), 9 * 6); // 9 letters × 6 possible connections each
+}
+
+#[test]
+fn test_hexagon_game() {
+    let sides = sides_from_strs(&["ab", "cd", "ef", "gh", "ij", "kl"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    assert_eq!(game.sides.len(), 6);
+    assert_eq!(game.digraphs.len(), 12 * 10); // 12 letters × 10 possible connections each
 }
 
 #[test]
 fn test_uneven_sides() {
     let sides = sides_from_strs(&["abc", "def", "ghij", "klm"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    assert!(game.digraphs.contains("ag"));
+    assert!(!game.digraphs.contains("gj"));
+    // "g" can reach the 9 letters on other sides, "a" can reach the 10 letters not on its side
+    assert_eq!(game.digraphs.to_strings().iter().filter(|d| d.starts_with('g')).count(), 9);
+    assert_eq!(game.digraphs.to_strings().iter().filter(|d| d.starts_with('a')).count(), 10);
+}
+
+#[test]
+fn test_empty_side() {
+    let sides = sides_from_strs(&["abc", "", "ghi", "jkl"]);
     let result = Board::from_sides(sides);
 
-    assert!(result.is_err());
-    assert!(result.unwrap_err().to_string().contains("same length"));
+    assert!(matches!(result, Err(Error::EmptySide { side: Side { index: 1, .. } })));
+    assert!(result.unwrap_err().to_string().contains("Empty sides"));
 }
 
 #[test]
@@ -40,6 +71,29 @@ fn test_duplicate_letters() {
     assert!(result.unwrap_err().to_string().contains("Duplicate letter"));
 }
 
+#[test]
+fn test_duplicate_letters_names_sides() {
+    let square = Board::from_sides(sides_from_strs(&["abc", "def", "gha", "jkl"])).unwrap_err();
+    assert!(square.to_string().contains("the top side and the bottom side"));
+
+    let pentagon = Board::from_sides(sides_from_strs(&["abc", "def", "gha", "jkl", "mno"])).unwrap_err();
+    assert!(pentagon.to_string().contains("side 1 and side 3"));
+}
+
+#[test]
+fn test_duplicate_letters_error_fields() {
+    let result = Board::from_sides(sides_from_strs(&["abc", "def", "gha", "jkl"]));
+
+    match result {
+        Err(Error::DuplicateLetter { ch, sides: (first, second) }) => {
+            assert_eq!(ch, 'a');
+            assert_eq!(first, Side { index: 0, side_count: 4 });
+            assert_eq!(second, Side { index: 2, side_count: 4 });
+        }
+        other => panic!("Expected a duplicate letter error, got {:?}", other),
+    }
+}
+
 #[test]
 fn test_duplicate_letters_same_side() {
     let sides = sides_from_strs(&["abc", "def", "ghi", "jkj"]); // 'J' appears twice on the same side
@@ -54,7 +108,7 @@ fn test_case() {
     let sides = sides_from_strs(&["ABC", "DEF", "ghi", "jkl"]);
     let result = Board::from_sides(sides);
 
-    assert!(result.is_err());
+    assert!(matches!(result, Err(Error::InvalidChar { ch: 'A', side: Side { index: 0, .. } })));
     assert!(result.unwrap_err().to_string().contains("lowercase"));
 }
 
@@ -94,3 +148,13 @@ fn test_from_sides_invalid_duplicate_letters() {
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("Duplicate letter"));
 }
+
+#[test]
+fn test_more_letters_than_alphabet() {
+    // Six sides of six letters would need 36 distinct letters, so some letter must repeat
+    let sides = sides_from_strs(&["abcdef", "ghijkl", "mnopqr", "stuvwx", "yzabcd", "efghij"]);
+    let result = Board::from_sides(sides);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Duplicate letter"));
+}
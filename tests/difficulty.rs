@@ -0,0 +1,26 @@
+use letter_bounced::board::Board;
+use letter_bounced::dictionary::Dictionary;
+use letter_bounced::difficulty::{Difficulty, DifficultyRating};
+use letter_bounced::playable::PlayableIndex;
+
+mod common;
+use common::sides_from_strs;
+
+#[test]
+fn test_rate_board() {
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+    let board = Board::from_sides(sides_from_strs(&["dae", "zru", "yfm", "cjk"])).unwrap();
+    let playable_words = board.playable_dictionary(&dictionary).words.len();
+
+    let rating = DifficultyRating::new(&PlayableIndex::new(board, &dictionary), 4);
+    assert_eq!(rating.inputs.fewest_words, Some(4));
+    assert_eq!(rating.inputs.solutions_by_length, vec![(1, 0), (2, 0), (3, 0), (4, 22)]);
+    assert_eq!(rating.inputs.playable_words, playable_words);
+    assert_eq!(rating.difficulty, Difficulty::Hard);
+
+    // Allowed only 3 words, there's no solution at all
+    let board = Board::from_sides(sides_from_strs(&["dae", "zru", "yfm", "cjk"])).unwrap();
+    let rating = DifficultyRating::new(&PlayableIndex::new(board, &dictionary), 3);
+    assert_eq!(rating.inputs.fewest_words, None);
+    assert!(rating.inputs.solutions_by_length.is_empty());
+}
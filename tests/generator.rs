@@ -0,0 +1,48 @@
+use letter_bounced::dictionary::Dictionary;
+use letter_bounced::generator::Generator;
+use letter_bounced::Error;
+
+#[test]
+fn test_generated_boards_have_common_answers() {
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+
+    for seed in 0..5 {
+        let puzzle = Generator::new(&dictionary, seed).generate().unwrap().unwrap();
+        let letters: String = puzzle.board.sides.concat();
+        assert_eq!(puzzle.board.sides.len(), 4);
+        assert!(puzzle.board.sides.iter().all(|side| side.len() == 3));
+
+        let vowels = letters.chars().filter(|&ch| "aeiou".contains(ch)).count();
+        assert!((3..=5).contains(&vowels), "{} has {} vowels", letters, vowels);
+        assert!(letters.chars().filter(|&ch| "jkqxz".contains(ch)).count() <= 1);
+
+        let words: Vec<&str> = puzzle.answer.words.iter().map(|w| w.word.as_str()).collect();
+        assert_eq!(words.len(), 2);
+        assert!(puzzle.answer.words.iter().all(|w| w.frequency >= Generator::DEFAULT_MIN_FREQUENCY));
+        assert!(puzzle.board.check_answer(&words, &dictionary).is_valid());
+    }
+}
+
+#[test]
+fn test_same_seed_same_board() {
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+    let generate = |seed| Generator::new(&dictionary, seed).generate().unwrap().unwrap().board.sides;
+
+    assert_eq!(generate(7), generate(7));
+    assert_ne!(generate(7), generate(8));
+}
+
+#[test]
+fn test_impossible_constraints() {
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+    assert!(Generator::new(&dictionary, 1).with_min_frequency(i8::MAX).generate().unwrap().is_none());
+    // No pair of words covers 12 letters without a vowel
+    assert!(Generator::new(&dictionary, 1).with_vowels(0..=0, 0).generate().unwrap().is_none());
+}
+
+#[test]
+fn test_invalid_limits() {
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+    let result = Generator::new(&dictionary, 1).with_vowels(6..=6, 2).generate();
+    assert!(matches!(result, Err(Error::InvalidGeneratorConfig { .. })));
+}
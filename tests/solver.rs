@@ -1,6 +1,8 @@
 use letter_bounced::board::Board;
-use letter_bounced::solver::Solver;
+use letter_bounced::solver::{SearchCursor, SearchStep, Solver};
 use letter_bounced::dictionary::Dictionary;
+use letter_bounced::playable::PlayableIndex;
+use letter_bounced::Error;
 
 mod common;
 use common::sides_from_strs;
@@ -29,3 +31,316 @@ fn test_solver_basic() {
         .iter()
         .any(|s| s.to_string() == "filtration-nag-gawkily"));
 }
+
+#[test]
+fn test_solver_uneven_triangle() {
+    let sides = sides_from_strs(&["fkwn", "oltag", "riy"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    let words = vec![
+        "forklift".to_string(),
+        "twangy".to_string(),
+        "going".to_string(),
+        "gawkily".to_string(),
+    ];
+    let wordlist = Dictionary::from_strings(words);
+    let solver = Solver::new(game, &wordlist, 10);
+    let solutions = solver.solve();
+
+    // "going" and "gawkily" are not playable because their opening letters share a side
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "forklift-twangy");
+}
+
+#[test]
+fn test_solver_from_updated_index() {
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+    let game = Board::from_sides(sides_from_strs(&["yfa", "otk", "lgw", "rnx"])).unwrap();
+    let mut index = PlayableIndex::new(game, &dictionary);
+
+    // Fix the typo in the last side
+    index.replace_letter('x', 'i').unwrap();
+    let fixed = Board::from_sides(sides_from_strs(&["yfa", "otk", "lgw", "rni"])).unwrap();
+
+    let from_index: Vec<String> = Solver::from_index(&index, 50).solve().iter().map(|s| s.to_string()).collect();
+    let from_scratch: Vec<String> = Solver::new(fixed, &dictionary, 50).solve().iter().map(|s| s.to_string()).collect();
+    assert_eq!(from_index, from_scratch);
+    assert_eq!(from_index[0], "forklift-twangy");
+}
+
+#[test]
+fn test_solutions_iterator() {
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    let words = vec![
+        "forklift".to_string(),
+        "twangy".to_string(),
+        "filtration".to_string(),
+        "nag".to_string(),
+        "gawkily".to_string(),
+    ];
+    let wordlist = Dictionary::from_strings(words);
+    let solver = Solver::new(game, &wordlist, 10);
+
+    // Shortest solutions come first, in the order they are found
+    let found: Vec<String> = solver.solutions().map(|s| s.to_string()).collect();
+    assert_eq!(found, vec!["forklift-twangy", "filtration-nag-gawkily"]);
+
+    // We can stop as soon as we have what we need
+    let mut solutions = solver.solutions();
+    assert_eq!(solutions.next().unwrap().to_string(), "forklift-twangy");
+    assert_eq!(solutions.target_words(), 2);
+    assert_eq!(solutions.next().unwrap().to_string(), "filtration-nag-gawkily");
+    assert_eq!(solutions.target_words(), 3);
+}
+
+#[test]
+fn test_solutions_advance_in_small_steps() {
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+    let words = ["forklift", "twangy", "filtration", "nag", "gawkily"];
+    let wordlist = Dictionary::from_strings(words.iter().map(|w| w.to_string()).collect());
+    let solver = Solver::new(game, &wordlist, 10);
+
+    // A step at a time finds the same solutions as running straight through
+    let mut search = solver.solutions();
+    let mut found = Vec::new();
+    let mut pauses = 0;
+    loop {
+        match search.advance(1) {
+            SearchStep::Found(solution) => found.push(solution.to_string()),
+            SearchStep::Paused => pauses += 1,
+            SearchStep::Finished => break,
+        }
+    }
+    assert_eq!(found, vec!["forklift-twangy", "filtration-nag-gawkily"]);
+    assert!(pauses > 0);
+}
+
+#[test]
+fn test_solve_from_pages() {
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+    let words = ["forklift", "twangy", "filtration", "nag", "gawkily", "fig", "girl"];
+    let wordlist = Dictionary::from_strings(words.iter().map(|w| w.to_string()).collect());
+    let solver = Solver::new(game, &wordlist, 10);
+    let all: Vec<String> = solver.solutions().map(|s| s.to_string()).collect();
+
+    // A page at a time, passing the cursor on as text, finds the same solutions
+    let mut paged = Vec::new();
+    let mut cursor: Option<SearchCursor> = None;
+    loop {
+        let page = solver.solve_from(cursor.as_ref(), 1).unwrap();
+        paged.extend(page.solutions.iter().map(|s| s.to_string()));
+        match page.next {
+            Some(next) => cursor = Some(next.to_string().parse().unwrap()),
+            None => break,
+        }
+    }
+    assert_eq!(paged, all);
+
+    // A cancelled search stops, but can still carry on from where it got to
+    let first = solver.solve_from(None, 1).unwrap().next.unwrap();
+    let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
+    let mut cancelled = solver.solutions_from_cancellable(&first, Some(cancel_flag)).unwrap();
+    assert!(cancelled.next().is_none());
+    let rest = solver.solve_from(cancelled.cursor().as_ref(), all.len()).unwrap();
+    assert_eq!(rest.solutions.iter().map(|s| s.to_string()).collect::<Vec<_>>(), all[1..]);
+
+    let too_long = SearchCursor {
+        target_words: 5,
+        positions: vec![],
+    };
+    assert!(matches!(solver.solve_from(Some(&too_long), 1), Err(Error::InvalidCursor { .. })));
+    let out_of_range = SearchCursor {
+        target_words: 2,
+        positions: vec![100],
+    };
+    assert!(matches!(solver.solve_from(Some(&out_of_range), 1), Err(Error::InvalidCursor { .. })));
+}
+
+#[test]
+fn test_solutions_iterator_cancelled() {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+    let wordlist = Dictionary::from_strings(vec!["forklift".to_string(), "twangy".to_string()]);
+    let solver = Solver::new(game, &wordlist, 10);
+
+    let cancel_flag = Arc::new(AtomicBool::new(true));
+    assert_eq!(solver.solutions_cancellable(Some(cancel_flag)).count(), 0);
+}
+
+#[test]
+fn test_solver_word_range() {
+    let sides = sides_from_strs(&["ab", "cd", "ef", "gh"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    // The only way to cover every letter takes five words
+    let words = ["ace", "eg", "gbd", "df", "fh"];
+    let wordlist = Dictionary::from_strings(words.iter().map(|w| w.to_string()).collect());
+
+    let solver = Solver::new(game.clone(), &wordlist, 10);
+    assert!(solver.solve().is_empty());
+
+    let solver = Solver::new(game, &wordlist, 10).with_word_range(1, 6);
+    let solutions = solver.solve();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "ace-eg-gbd-df-fh");
+}
+
+#[test]
+fn test_solver_min_words() {
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    let words = vec![
+        "forklift".to_string(),
+        "twangy".to_string(),
+        "filtration".to_string(),
+        "nag".to_string(),
+        "gawkily".to_string(),
+    ];
+    let wordlist = Dictionary::from_strings(words);
+    let solver = Solver::new(game, &wordlist, 10).with_word_range(3, 4);
+    let solutions = solver.solve();
+
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "filtration-nag-gawkily");
+}
+
+#[test]
+fn test_solver_with_scorer() {
+    use letter_bounced::scorer::LongestWord;
+
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+
+    let words = vec![
+        "forklift".to_string(),
+        "twangy".to_string(),
+        "filtration".to_string(),
+        "nag".to_string(),
+        "gawkily".to_string(),
+    ];
+    let wordlist = Dictionary::from_strings(words);
+    let solver = Solver::new(game, &wordlist, 10).with_scorer(Box::new(LongestWord));
+    let solutions = solver.solve();
+
+    // FILTRATION is the longest word, so its solution now comes first despite being longer
+    assert_eq!(solutions[0].to_string(), "filtration-nag-gawkily");
+    assert_eq!(solutions[0].score, 10);
+    assert_eq!(solutions[1].score, 8);
+}
+
+#[test]
+fn test_solve_best_matches_exhaustive_sort() {
+    use letter_bounced::scorer::{FewestLetters, LongestWord, MinFrequency, MostObscure, SolutionScorer};
+
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+
+    let scorers: Vec<fn() -> Box<dyn SolutionScorer>> = vec![
+        || Box::new(MinFrequency),
+        || Box::new(FewestLetters),
+        || Box::new(LongestWord),
+        || Box::new(MostObscure),
+    ];
+    for scorer in scorers {
+        // Every solution of up to three words, best first
+        let everything = Solver::new(game.clone(), &dictionary, u16::MAX)
+            .with_word_range(1, 3)
+            .with_scorer(scorer())
+            .solve();
+        assert!(everything.len() > 100 && everything.len() < u16::MAX.into());
+
+        for k in [1, 10, 100] {
+            let solver = Solver::new(game.clone(), &dictionary, k)
+                .with_word_range(1, 3)
+                .with_scorer(scorer());
+            assert_eq!(solver.solve_best(None), everything[..k as usize]);
+        }
+    }
+}
+
+#[test]
+fn test_best_shortest_solution() {
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+
+    let best = Solver::new(game.clone(), &dictionary, 1).best_shortest_solution().unwrap();
+    let two_words = Solver::new(game, &dictionary, u16::MAX).with_word_range(2, 2).solve();
+    assert_eq!(best.words.len(), 2);
+    assert_eq!(best, two_words[0]);
+}
+
+#[test]
+fn test_completions() {
+    let sides = sides_from_strs(&["yfa", "otk", "lgw", "rni"]);
+    let game = Board::from_sides(sides).unwrap();
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+    let solver = Solver::new(game, &dictionary, 10);
+
+    let completions: Vec<_> = solver.completions(&["forklift"]).unwrap().take(100).collect();
+    assert!(completions.iter().any(|s| s.to_string() == "twangy"));
+    for completion in &completions {
+        assert!(completion.words[0].word.starts_with('t'));
+    }
+    assert_eq!(solver.fewest_words_to_finish(&["forklift"]).unwrap(), Some(1));
+    assert_eq!(solver.fewest_words_to_finish(&["forklift", "twangy"]).unwrap(), Some(0));
+    assert_eq!(solver.fewest_words_to_finish(&[]).unwrap(), Some(2));
+
+    assert!(matches!(solver.completions(&["zebra"]), Err(Error::UnplayableWord { .. })));
+    assert!(matches!(solver.completions(&["forklift", "wanly"]), Err(Error::BrokenChain { .. })));
+}
+
+#[test]
+fn test_count_solutions() {
+    let sides = sides_from_strs(&["vyq", "fig", "ote", "xlu"]);
+    let game = Board::from_sides(sides).unwrap();
+    let dictionary = Dictionary::from_path("data/dictionary.txt").unwrap().0;
+
+    let solver = Solver::new(game, &dictionary, u16::MAX);
+    let counts = solver.count_solutions();
+    let solutions = solver.solve();
+
+    assert!(solutions.len() < u16::MAX.into());
+    assert_eq!(counts.total(), solutions.len() as u64);
+    for (words, count) in counts.iter() {
+        let found = solutions.iter().filter(|s| s.words.len() == words).count();
+        assert_eq!(count, found as u64, "{}-word solutions", words);
+    }
+    assert_eq!(counts.fewest_words(), Some(2));
+    assert_eq!(counts.with_words(1), 0);
+    assert_eq!(counts.with_words(9), 0);
+}
+
+#[test]
+fn test_count_solutions_with_long_chains() {
+    let sides = sides_from_strs(&["vyq", "fig", "ote", "xlu"]);
+    let game = Board::from_sides(sides).unwrap();
+    let words = [
+        "oil", "exit", "lively", "quit", "fog", "futile", "ugly", "vivo", "folio", "lieu", "tit", "futility", "ole",
+        "eft", "flit", "gouge", "gov", "tut", "vive", "leve", "oxo", "voile", "elt", "levo", "eloge", "vext", "foley",
+        "ixtle", "oxeye", "ovel", "elogy", "euge", "fley", "flix", "outlie", "foxily", "gox", "exequy", "foxie",
+        "yuft", "tufoli", "voguey",
+    ];
+    let wordlist = Dictionary::from_strings(words.iter().map(|w| w.to_string()).collect());
+
+    let solver = Solver::new(game, &wordlist, u16::MAX).with_word_range(1, 7);
+    let counts = solver.count_solutions();
+    let solutions: Vec<_> = solver.solutions().collect();
+
+    assert_eq!(counts.total(), solutions.len() as u64);
+    for (words, count) in counts.iter() {
+        let found = solutions.iter().filter(|s| s.words.len() == words).count();
+        assert_eq!(count, found as u64, "{}-word solutions", words);
+    }
+    assert!(counts.with_words(6) > 0);
+    assert!(counts.with_words(7) > 0);
+}
@@ -1,11 +1,12 @@
 import { writable, derived, type Writable } from 'svelte/store';
+import type { Solution } from '../types';
 
 // Puzzle fields store - array of 12 individual letters
 // Layout: [0-2: top, 3-5: right, 6-8: left, 9-11: bottom]
 export const puzzleFields: Writable<string[]> = writable(Array(12).fill(''));
 
-// Solutions store - array of solution strings
-export const solutions: Writable<string[]> = writable([]);
+// Solutions store - array of solution objects
+export const solutions: Writable<Solution[]> = writable([]);
 
 // Solver state
 export const solverReady: Writable<boolean> = writable(false);
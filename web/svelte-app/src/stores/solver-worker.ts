@@ -1,4 +1,5 @@
 import { writable, type Writable } from 'svelte/store';
+import type { Solution, SolveProgress } from '../types';
 
 interface SolveStats {
   totalCount: number;
@@ -8,7 +9,9 @@ interface SolveStats {
 interface WorkerMessage {
   type: string;
   solveId?: number;
-  solutions?: string[];
+  solutions?: Solution[];
+  progress?: SolveProgress;
+  cursor?: string;
   totalCount?: number;
   duration?: number;
   error?: string;
@@ -16,13 +19,33 @@ interface WorkerMessage {
 
 export const solverReady: Writable<boolean> = writable(false);
 export const solving: Writable<boolean> = writable(false);
-export const solutions: Writable<string[]> = writable([]);
+export const solutions: Writable<Solution[]> = writable([]);
+export const solveProgress: Writable<SolveProgress | null> = writable(null);
+// Where the last solve or page stopped, if it stopped before finding every solution
+export const moreCursor: Writable<string | null> = writable(null);
+export const loadingMore: Writable<boolean> = writable(false);
 export const solveStats: Writable<SolveStats> = writable({ totalCount: 0, duration: null });
 export const solverError: Writable<string | null> = writable(null);
 
 let currentSolveId = 0;
 let worker: Worker | null = null;
 
+interface SolveParams {
+  sides: string[];
+  maxSolutions: number;
+  minWords: number;
+  maxWords: number;
+}
+
+// What the current solve was asked for, so that more pages can be asked for the same way
+let currentParams: SolveParams | null = null;
+
+// Solutions arrive in the order the search finds them, but are shown best first. The sort is stable,
+// so solutions with the same score keep the order they were found in
+function bestFirst(found: Solution[]): Solution[] {
+  return [...found].sort((a, b) => b.score - a.score);
+}
+
 export function initializeSolverWorker(dictionaryData: Uint8Array): void {
   worker = new Worker(
     new URL('../workers/solver-worker.ts', import.meta.url),
@@ -30,7 +53,7 @@ export function initializeSolverWorker(dictionaryData: Uint8Array): void {
   );
 
   worker.addEventListener('message', (e: MessageEvent<WorkerMessage>) => {
-    const { type, solveId, solutions: receivedSolutions, totalCount, duration, error } = e.data;
+    const { type, solveId, solutions: receivedSolutions, progress, cursor, totalCount, duration, error } = e.data;
 
     console.log(`[Store] Received ${type} message, solveId=${solveId}, currentSolveId=${currentSolveId}`);
 
@@ -38,12 +61,25 @@ export function initializeSolverWorker(dictionaryData: Uint8Array): void {
       solverReady.set(true);
     }
 
+    if (type === 'PROGRESS') {
+      // Keep the solutions found so far sorted, until COMPLETE replaces them with the final list.
+      // A batch may have no solutions, just progress
+      if (solveId === currentSolveId && receivedSolutions && progress) {
+        if (receivedSolutions.length > 0) {
+          solutions.update((found) => bestFirst([...found, ...receivedSolutions]));
+        }
+        solveProgress.set(progress);
+      }
+    }
+
     if (type === 'COMPLETE') {
       console.log(`[Store] COMPLETE check: solveId=${solveId}, currentSolveId=${currentSolveId}, match=${solveId === currentSolveId}, solutions count=${receivedSolutions?.length}`);
       if (solveId === currentSolveId && receivedSolutions) {
         console.log(`[Store] Setting solutions, count=${receivedSolutions.length}`);
         // Solutions are already sorted by score from the Rust solver
         solutions.set(receivedSolutions);
+        moreCursor.set(cursor ?? null);
+        solveProgress.set(null);
         solving.set(false);
         if (totalCount !== undefined && duration !== undefined) {
           solveStats.set({ totalCount, duration });
@@ -53,15 +89,31 @@ export function initializeSolverWorker(dictionaryData: Uint8Array): void {
       }
     }
 
+    if (type === 'PAGE') {
+      // More solutions, sorted in among those already shown
+      if (solveId === currentSolveId && receivedSolutions) {
+        solutions.update((found) => bestFirst([...found, ...receivedSolutions]));
+        moreCursor.set(cursor ?? null);
+        loadingMore.set(false);
+        solveStats.update((stats) => ({ ...stats, totalCount: stats.totalCount + receivedSolutions.length }));
+      }
+    }
+
     if (type === 'CANCELLED') {
       console.log(`Solve ${solveId} was cancelled.`);
-      solving.set(false);
+      if (solveId === currentSolveId) {
+        solveProgress.set(null);
+        solving.set(false);
+        loadingMore.set(false);
+      }
     }
 
     if (type === 'ERROR') {
       console.error('Solver error:', error);
       solverError.set(error ?? 'Unknown error');
+      solveProgress.set(null);
       solving.set(false);
+      loadingMore.set(false);
     }
   });
 
@@ -71,7 +123,7 @@ export function initializeSolverWorker(dictionaryData: Uint8Array): void {
   });
 }
 
-export function solvePuzzle(sides: string[], maxSolutions = 10000): void {
+export function solvePuzzle(sides: string[], maxSolutions = 10000, minWords = 1, maxWords = 4): void {
   if (!worker) {
     console.error('Worker not initialized');
     return;
@@ -79,21 +131,42 @@ export function solvePuzzle(sides: string[], maxSolutions = 10000): void {
 
   currentSolveId++;
   console.log(`[Store] solvePuzzle called, new solveId=${currentSolveId}, sides=${sides.join(',')}`);
+  currentParams = { sides, maxSolutions, minWords, maxWords };
   solving.set(true);
   solutions.set([]);
+  solveProgress.set(null);
+  moreCursor.set(null);
+  loadingMore.set(false);
   solveStats.set({ totalCount: 0, duration: null });
   solverError.set(null);
 
   worker.postMessage({
     type: 'SOLVE',
     solveId: currentSolveId,
-    payload: { sides, maxSolutions }
+    payload: { sides, maxSolutions, minWords, maxWords }
+  });
+}
+
+// Carry on the current solve from where it stopped, for as many solutions again
+export function loadMoreSolutions(cursor: string): void {
+  if (!worker || !currentParams) {
+    console.error('Nothing to load more solutions for');
+    return;
+  }
+
+  loadingMore.set(true);
+  worker.postMessage({
+    type: 'MORE',
+    solveId: currentSolveId,
+    payload: { ...currentParams, cursor }
   });
 }
 
 export function cancelSolve(): void {
   if (worker) {
     worker.postMessage({ type: 'CANCEL' });
+    solveProgress.set(null);
     solving.set(false);
+    loadingMore.set(false);
   }
 }
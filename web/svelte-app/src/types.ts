@@ -0,0 +1,33 @@
+// Shapes of the values the WASM solver hands back
+
+export interface SolutionWord {
+  word: string;
+  frequency: number;
+  // Letters this word covers that no earlier word in the solution did
+  newLetters: string;
+}
+
+export interface Solution {
+  words: SolutionWord[];
+  score: number;
+}
+
+export interface SolveProgress {
+  // How many words the solutions being searched for have
+  targetWords: number;
+  // Solutions found so far
+  found: number;
+  elapsedMs: number;
+}
+
+// What the solver hands over every so often while it searches
+export interface SolveBatch {
+  solutions: Solution[];
+  progress: SolveProgress;
+}
+
+// What solve_game and solve_page resolve to. Pass the cursor to solve_page for more; there's none after the last
+export interface SolutionPage {
+  solutions: Solution[];
+  cursor?: string;
+}
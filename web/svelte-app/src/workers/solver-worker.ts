@@ -1,19 +1,26 @@
-import init, { initialize_dictionary, solve_game, cancel_current_solve } from '../pkg/letter_bounced.js';
+import init, { initialize_dictionary, solve_game, solve_page, cancel_current_solve } from '../pkg/letter_bounced.js';
+import type { Solution, SolutionPage, SolveBatch, SolveProgress } from '../types';
 
 interface WorkerMessageData {
-  type: 'INIT' | 'CANCEL' | 'SOLVE';
+  type: 'INIT' | 'CANCEL' | 'SOLVE' | 'MORE';
   payload?: {
     dictionaryData?: Uint8Array;
     sides?: string[];
     maxSolutions?: number;
+    minWords?: number;
+    maxWords?: number;
+    // For MORE: where the last solve or page stopped
+    cursor?: string;
   };
   solveId?: number;
 }
 
 interface OutgoingMessage {
-  type: 'READY' | 'COMPLETE' | 'CANCELLED' | 'ERROR';
+  type: 'READY' | 'PROGRESS' | 'COMPLETE' | 'PAGE' | 'CANCELLED' | 'ERROR';
   solveId?: number;
-  solutions?: string[];
+  solutions?: Solution[];
+  progress?: SolveProgress;
+  cursor?: string;
   totalCount?: number;
   duration?: number;
   error?: string;
@@ -26,6 +33,33 @@ let wasmReady: Promise<void> = new Promise((resolve) => {
 });
 let currentSolveId: number | null = null;
 
+// Tell the store a solve or page failed, unless it has since been replaced or cancelled
+function reportFailure(solveId: number | undefined, error: unknown): void {
+  const errorMessage = error instanceof Error ? error.message : String(error);
+
+  console.log(`[Worker] Solve failed for solveId=${solveId}, error=${errorMessage}`);
+
+  // A solve which has since been replaced or cancelled has nothing more to say
+  if (currentSolveId !== solveId) {
+    return;
+  }
+
+  // Check if it was a cancellation
+  if (errorMessage === 'Cancelled') {
+    self.postMessage({
+      type: 'CANCELLED',
+      solveId
+    } as OutgoingMessage);
+  } else {
+    self.postMessage({
+      type: 'ERROR',
+      solveId,
+      error: errorMessage
+    } as OutgoingMessage);
+  }
+  currentSolveId = null;
+}
+
 self.addEventListener('message', async (e: MessageEvent<WorkerMessageData>) => {
   const { type, payload, solveId } = e.data;
 
@@ -56,22 +90,36 @@ self.addEventListener('message', async (e: MessageEvent<WorkerMessageData>) => {
 
     console.log(`[Worker] Received SOLVE request, solveId=${solveId}, sides=${payload?.sides?.join(',')}`);
 
-    // Note: The WASM layer now handles cancellation automatically when a new solve
-    // with different params is requested. We just track solveId for message correlation.
+    // Note: The WASM layer cancels any solve still in progress when a new one starts, since it
+    // searches in slices and lets this handler run in between. We just track solveId for message correlation.
     currentSolveId = solveId ?? null;
     const sides = payload?.sides ?? [];
     const maxSolutions = payload?.maxSolutions ?? 10000;
+    const minWords = payload?.minWords ?? 1;
+    const maxWords = payload?.maxWords ?? 4;
 
     try {
       const startTime = performance.now();
 
       console.log(`[Worker] Calling solve_game for solveId=${solveId}`);
+      // Pass on each batch of solutions as it's found, while this solve is still current
+      const onBatch = (batch: SolveBatch) => {
+        if (currentSolveId === solveId) {
+          self.postMessage({
+            type: 'PROGRESS',
+            solveId,
+            solutions: batch.solutions,
+            progress: batch.progress
+          } as OutgoingMessage);
+        }
+      };
+
       // Call the Promise-based solve_game
-      const solutions = await solve_game(sides, maxSolutions);
+      const page: SolutionPage = await solve_game(sides, maxSolutions, minWords, maxWords, onBatch);
       const duration = Math.round(performance.now() - startTime);
 
-      // Convert JS array to regular array of strings
-      const solutionsArray = Array.from(solutions);
+      // Solutions arrive as plain objects, ready to post as they are
+      const solutionsArray: Solution[] = page.solutions;
 
       console.log(`[Worker] solve_game completed for solveId=${solveId}, solutions=${solutionsArray.length}, currentSolveId=${currentSolveId}`);
 
@@ -82,6 +130,7 @@ self.addEventListener('message', async (e: MessageEvent<WorkerMessageData>) => {
           type: 'COMPLETE',
           solveId,
           solutions: solutionsArray,
+          cursor: page.cursor,
           totalCount: solutionsArray.length,
           duration
         } as OutgoingMessage);
@@ -90,24 +139,33 @@ self.addEventListener('message', async (e: MessageEvent<WorkerMessageData>) => {
         console.log(`[Worker] NOT sending COMPLETE - currentSolveId changed to ${currentSolveId}`);
       }
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      reportFailure(solveId, error);
+    }
+  }
 
-      console.log(`[Worker] solve_game failed for solveId=${solveId}, error=${errorMessage}`);
+  if (type === 'MORE') {
+    await wasmReady;
 
-      // Check if it was a cancellation
-      if (errorMessage === 'Cancelled' || errorMessage.includes('already in progress')) {
-        self.postMessage({
-          type: 'CANCELLED',
-          solveId
-        } as OutgoingMessage);
-      } else {
+    // Carries on the search of the solve with the same id, which the store keeps track of
+    currentSolveId = solveId ?? null;
+    const sides = payload?.sides ?? [];
+    const pageSize = payload?.maxSolutions ?? 10000;
+    const minWords = payload?.minWords ?? 1;
+    const maxWords = payload?.maxWords ?? 4;
+
+    try {
+      const page: SolutionPage = await solve_page(sides, payload?.cursor, pageSize, minWords, maxWords);
+      if (currentSolveId === solveId) {
         self.postMessage({
-          type: 'ERROR',
+          type: 'PAGE',
           solveId,
-          error: errorMessage
+          solutions: page.solutions,
+          cursor: page.cursor
         } as OutgoingMessage);
+        currentSolveId = null;
       }
-      currentSolveId = null;
+    } catch (error) {
+      reportFailure(solveId, error);
     }
   }
 });